@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::input::resolve_path;
+use crate::solver::Part;
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+// the expected answers for the real inputs, read from a small subset of toml
+//
+// [day1]
+// part1 = 70374
+// part2 = "some text"
+#[derive(Debug, Clone, Default)]
+pub struct Answers {
+    pub expected: HashMap<(usize, Part), String>,
+}
+
+fn parse_value(value: &str) -> Option<String> {
+    if let Some(value) = value.strip_prefix('"') {
+        let value = value.strip_suffix('"')?;
+        if value.contains('"') || value.contains('\\') {
+            return None;
+        }
+        return Some(value.to_string());
+    }
+    // numbers are compared as text, toml allows _ as separator
+    let number = value.replace('_', "");
+    let digits = number.strip_prefix('-').unwrap_or(&number);
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some(number)
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Answers, String> {
+        let mut answers = Answers::default();
+        let mut day = None;
+
+        for (y, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(table) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                let d = table.trim().strip_prefix("day").and_then(|d| d.parse::<usize>().ok());
+                day = Some(d.ok_or_else(|| format!("line {}: invalid table '{}'", y + 1, line))?);
+                continue;
+            }
+            let d = day.ok_or_else(|| format!("line {}: answer outside of a [dayN] table", y + 1))?;
+            let (key, value) = line.split_once('=').ok_or_else(|| format!("line {}: missing '='", y + 1))?;
+            let part = match key.trim() {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                key => return Err(format!("line {}: invalid key '{}'", y + 1, key)),
+            };
+            // strip a trailing comment, answers themselves never contain a '#'
+            let value = value.split('#').next().unwrap().trim();
+            let value = parse_value(value).ok_or_else(|| format!("line {}: invalid value '{}'", y + 1, value))?;
+            if answers.expected.insert((d, part), value).is_some() {
+                return Err(format!("line {}: day {} part {} is already set", y + 1, d, part));
+            }
+        }
+        Ok(answers)
+    }
+
+    pub fn get(&self, day: usize, part: Part) -> Option<&str> {
+        self.expected.get(&(day, part)).map(|s| s.as_str())
+    }
+}
+
+// path is the value of --answers, without it answers.toml of the crate is used
+pub fn locate_answers(path: Option<&str>) -> PathBuf {
+    match path {
+        Some(path) => PathBuf::from(path),
+        None => resolve_path(ANSWERS_FILE),
+    }
+}
+
+pub fn read_answers(path: &Path) -> Result<Answers, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+    Answers::parse(&text).map_err(|e| format!("{} {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{locate_answers, read_answers, Answers};
+    use crate::solver::Part;
+
+    #[test]
+    fn it_works() {
+        let text = "# comment\n[day1]\npart1 = 70_374\npart2 = \"BRJLFULP\" # letters\n\n[day25]\npart1 = \"2=-1\"\n";
+        let answers = Answers::parse(text).unwrap();
+        assert_eq!(answers.get(1, Part::One), Some("70374"));
+        assert_eq!(answers.get(1, Part::Two), Some("BRJLFULP"));
+        assert_eq!(answers.get(25, Part::One), Some("2=-1"));
+        assert_eq!(answers.get(25, Part::Two), None);
+
+        assert_eq!(Answers::parse("part1 = 1").unwrap_err(), "line 1: answer outside of a [dayN] table");
+        assert_eq!(Answers::parse("[day1]\npart3 = 1").unwrap_err(), "line 2: invalid key 'part3'");
+        assert_eq!(Answers::parse("[day1]\npart1 = 1x").unwrap_err(), "line 2: invalid value '1x'");
+        assert_eq!(Answers::parse("[day1]\npart1 = 1\npart1 = 2").unwrap_err(), "line 3: day 1 part 1 is already set");
+
+        let answers = read_answers(&locate_answers(None)).unwrap();
+        assert_eq!(answers.get(11, Part::One), Some("66124"));
+        assert_eq!(answers.get(10, Part::Two), Some("BRJLFULP"));
+    }
+}
@@ -0,0 +1,147 @@
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+use crate::solver::{solve_day_timed, Options, Part};
+use crate::Error;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    // None for no samples
+    pub fn new(samples: &[Duration]) -> Option<Stats> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        if n == 0 {
+            return None;
+        }
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+        Some(Stats {
+            min: sorted[0],
+            median,
+            max: sorted[n - 1],
+        })
+    }
+}
+
+fn ms(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "min {:>10.3} ms  median {:>10.3} ms  max {:>10.3} ms", ms(self.min), ms(self.median), ms(self.max))
+    }
+}
+
+// the timings of one day, every step measured over the same runs
+#[derive(Debug, Clone)]
+pub struct DayBench {
+    pub day: usize,
+    pub runs: usize,
+    pub parse: Stats,
+    pub parts: Vec<(Part, Stats)>,
+}
+
+// None if there is no such day
+pub fn bench_day(day: usize, lines: &[String], parts: &[Part], runs: usize, options: &Options) -> Option<Result<DayBench, Error>> {
+    let runs = runs.max(1);
+    let mut parse_times = vec![];
+    let mut part_times = vec![vec![]; parts.len()];
+
+    for _ in 0..runs {
+        let solution = match solve_day_timed(day, lines, parts, options)? {
+            Ok(solution) => solution,
+            Err(e) => return Some(Err(e)),
+        };
+        parse_times.push(solution.parse_time);
+        for (times, (_, answer, elapsed)) in part_times.iter_mut().zip(solution.answers) {
+            if let Err(e) = answer {
+                return Some(Err(e));
+            }
+            times.push(elapsed);
+        }
+    }
+
+    Some(Ok(DayBench {
+        day,
+        runs,
+        parse: Stats::new(&parse_times).unwrap(),
+        parts: parts.iter().zip(part_times).map(|(part, times)| (*part, Stats::new(&times).unwrap())).collect(),
+    }))
+}
+
+impl DayBench {
+    // every measured step as (name, stats)
+    fn steps(&self) -> Vec<(String, Stats)> {
+        let mut steps = vec![("parse".to_string(), self.parse)];
+        for (part, stats) in self.parts.iter() {
+            steps.push((format!("part{}", part), *stats));
+        }
+        steps
+    }
+}
+
+// one object per day, durations in nanoseconds
+pub fn to_json(benches: &[DayBench]) -> String {
+    let days = benches.iter().map(|bench| {
+        let steps = bench.steps().iter().map(|(name, stats)| {
+            format!("\"{}\": {{\"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+                    name, stats.min.as_nanos(), stats.median.as_nanos(), stats.max.as_nanos())
+        }).collect::<Vec<_>>();
+        format!("    {{\"day\": {}, \"runs\": {}, {}}}", bench.day, bench.runs, steps.join(", "))
+    }).collect::<Vec<_>>();
+    format!("{{\n  \"days\": [\n{}\n  ]\n}}\n", days.join(",\n"))
+}
+
+// one row per day and step, durations in nanoseconds
+pub fn to_csv(benches: &[DayBench]) -> String {
+    let mut csv = "day,step,runs,min_ns,median_ns,max_ns\n".to_string();
+    for bench in benches.iter() {
+        for (name, stats) in bench.steps() {
+            csv += &format!("{},{},{},{},{},{}\n",
+                            bench.day, name, bench.runs, stats.min.as_nanos(), stats.median.as_nanos(), stats.max.as_nanos());
+        }
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bench_day, to_csv, to_json, Stats};
+    use crate::solver::{Options, Part};
+    use std::time::Duration;
+
+    #[test]
+    fn it_works() {
+        let samples = [3, 1, 2, 10].iter().map(|ms| Duration::from_millis(*ms)).collect::<Vec<_>>();
+        let stats = Stats::new(&samples).unwrap();
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(2) + Duration::from_micros(500));
+        assert_eq!(stats.max, Duration::from_millis(10));
+        assert!(Stats::new(&[]).is_none());
+
+        let lines = ["A Y",
+                     "B X",
+                     "C Z"].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let bench = bench_day(2, &lines, &[Part::One, Part::Two], 3, &Options::default()).unwrap().unwrap();
+        assert_eq!(bench.runs, 3);
+        assert_eq!(bench.parts.len(), 2);
+
+        let benches = vec![bench];
+        let csv = to_csv(&benches);
+        assert_eq!(csv.lines().count(), 4);
+        assert!(csv.lines().nth(2).unwrap().starts_with("2,part1,3,"));
+        let json = to_json(&benches);
+        assert!(json.contains("{\"day\": 2, \"runs\": 3, \"parse\": {\"min_ns\": "));
+    }
+}
@@ -0,0 +1,356 @@
+use aoc_2022::bench::{bench_day, to_csv, to_json};
+use aoc_2022::answers::{locate_answers, read_answers, Answers};
+use aoc_2022::input::{locate_example, locate_input};
+use aoc_2022::solver::{solve_day, solve_day_timed, Options, Part, DAYS};
+use std::env;
+use std::fs;
+use std::process::ExitCode;
+use std::time::Duration;
+
+const USAGE: &str = "usage: aoc run <day|all> [--part 1|2] [--input path|-] [--example] [--threads n]
+       aoc verify [day|all] [--answers path]
+       aoc bench <day|all> [--part 1|2] [--runs n] [--json path] [--csv path] [--threads n]
+
+inputs are read from $AOC_INPUT_DIR or input/ as input_dayN.txt
+--input -   reads the input from stdin
+--example   uses input_test/input_dayN_test.txt
+--answers   expected answers, answers.toml by default
+--runs      how often every day is run, 10 by default
+--threads   threads for the days that work in parallel (day 19), one per core by default
+--json      writes the benchmark report as json
+--csv       writes the benchmark report as csv";
+
+#[derive(Debug)]
+struct RunArgs {
+    days: Vec<usize>,
+    parts: Vec<Part>,
+    input: Option<String>,
+    example: bool,
+    options: Options,
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+    let mut run_args = RunArgs {
+        days: vec![],
+        parts: vec![Part::One, Part::Two],
+        input: None,
+        example: false,
+        options: Options::default(),
+    };
+
+    let mut args_iter = args.iter();
+    match args_iter.next() {
+        Some(day) => run_args.days = parse_day_arg(day)?,
+        None => return Err("missing day".to_string()),
+    }
+
+    while let Some(arg) = args_iter.next() {
+        match arg.as_str() {
+            "--part" => {
+                let part = args_iter.next().ok_or("missing value for --part")?;
+                let part = Part::try_from(part.as_str())?;
+                run_args.parts = vec![part];
+            }
+            "--input" => {
+                let input = args_iter.next().ok_or("missing value for --input")?;
+                run_args.input = Some(input.to_string());
+            }
+            "--example" => run_args.example = true,
+            "--threads" => run_args.options.threads = Some(parse_threads(args_iter.next())?),
+            _ => return Err(format!("unknown argument '{}'", arg)),
+        }
+    }
+
+    if run_args.input.is_some() && run_args.days.len() != 1 {
+        return Err("--input can only be used with a single day".to_string());
+    }
+    if run_args.input.is_some() && run_args.example {
+        return Err("--input and --example can not be combined".to_string());
+    }
+    Ok(run_args)
+}
+
+// 0 is left for one thread per core
+fn parse_threads(threads: Option<&String>) -> Result<usize, String> {
+    let threads = threads.ok_or("missing value for --threads")?;
+    threads.parse().ok().filter(|threads| *threads > 0).ok_or(format!("invalid threads '{}'", threads))
+}
+
+fn parse_day_arg(day: &str) -> Result<Vec<usize>, String> {
+    if day == "all" {
+        return Ok((1..DAYS + 1).collect());
+    }
+    let day: usize = day.parse().map_err(|_| format!("invalid day '{}'", day))?;
+    if day == 0 || day > DAYS {
+        return Err(format!("day must be between 1 and {}", DAYS));
+    }
+    Ok(vec![day])
+}
+
+fn run(run_args: &RunArgs) -> bool {
+    let mut ok = true;
+    for &day in run_args.days.iter() {
+        let input = if run_args.example {
+            locate_example(day)
+        } else {
+            locate_input(day, run_args.input.as_deref())
+        };
+        let lines = match input.read_lines() {
+            Ok(lines) => lines,
+            Err(e) => {
+                eprintln!("day {}: cannot read {}: {}", day, input, e);
+                ok = false;
+                continue;
+            }
+        };
+
+        let answers = match solve_day(day, &lines, &run_args.parts, &run_args.options).unwrap() {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("{}", e);
+                ok = false;
+                continue;
+            }
+        };
+        for (part, answer) in answers {
+            let answer = match answer {
+                Ok(answer) => answer,
+                Err(e) => {
+                    eprintln!("day {} part {}: {}", day, part, e);
+                    ok = false;
+                    continue;
+                }
+            };
+            if answer.contains('\n') {
+                println!("day {} part {}:\n{}", day, part, answer);
+            } else {
+                println!("day {} part {}: {}", day, part, answer);
+            }
+        }
+    }
+    ok
+}
+
+#[derive(Debug)]
+struct VerifyArgs {
+    days: Vec<usize>,
+    answers: Option<String>,
+}
+
+fn parse_verify_args(args: &[String]) -> Result<VerifyArgs, String> {
+    let mut verify_args = VerifyArgs {
+        days: (1..DAYS + 1).collect(),
+        answers: None,
+    };
+
+    let mut args_iter = args.iter();
+    while let Some(arg) = args_iter.next() {
+        match arg.as_str() {
+            "--answers" => {
+                let answers = args_iter.next().ok_or("missing value for --answers")?;
+                verify_args.answers = Some(answers.to_string());
+            }
+            day => verify_args.days = parse_day_arg(day)?,
+        }
+    }
+    Ok(verify_args)
+}
+
+fn format_duration(d: Duration) -> String {
+    format!("{:.3} ms", d.as_secs_f64() * 1000.0)
+}
+
+#[derive(Debug, Default)]
+struct VerifyCount {
+    pass: usize,
+    fail: usize,
+    unchecked: usize,
+    skipped: usize,
+}
+
+fn verify_day(day: usize, answers: &Answers, count: &mut VerifyCount) {
+    let input = locate_input(day, None);
+    let lines = match input.read_lines() {
+        Ok(lines) => lines,
+        Err(e) => {
+            println!("day {:>2}        skip      cannot read {}: {}", day, input, e);
+            count.skipped += 1;
+            return;
+        }
+    };
+
+    let solution = match solve_day_timed(day, &lines, &[Part::One, Part::Two], &Options::default()).unwrap() {
+        Ok(solution) => solution,
+        Err(e) => {
+            println!("day {:>2}        fail      {}", day, e);
+            count.fail += 1;
+            return;
+        }
+    };
+    for (part, answer, elapsed) in solution.answers {
+        let time = format_duration(elapsed);
+        let answer = match answer {
+            Ok(answer) => answer,
+            Err(e) => {
+                println!("day {:>2} part {} fail      {:>12}  {}", day, part, time, e);
+                count.fail += 1;
+                continue;
+            }
+        };
+        match answers.get(day, part) {
+            Some(expected) if expected == answer => {
+                println!("day {:>2} part {} pass      {:>12}", day, part, time);
+                count.pass += 1;
+            }
+            Some(expected) => {
+                println!("day {:>2} part {} mismatch  {:>12}  expected {} got {}", day, part, time, expected, answer);
+                count.fail += 1;
+            }
+            None => {
+                println!("day {:>2} part {} unchecked {:>12}  no answer recorded", day, part, time);
+                count.unchecked += 1;
+            }
+        }
+    }
+}
+
+// run every day against its real input and compare with the recorded answers
+fn verify(verify_args: &VerifyArgs) -> bool {
+    let path = locate_answers(verify_args.answers.as_deref());
+    let answers = match read_answers(&path) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+
+    let mut count = VerifyCount::default();
+    for &day in verify_args.days.iter() {
+        verify_day(day, &answers, &mut count);
+    }
+    println!("{} passed, {} failed, {} unchecked, {} skipped", count.pass, count.fail, count.unchecked, count.skipped);
+    count.fail == 0
+}
+
+#[derive(Debug)]
+struct BenchArgs {
+    days: Vec<usize>,
+    parts: Vec<Part>,
+    runs: usize,
+    json: Option<String>,
+    csv: Option<String>,
+    options: Options,
+}
+
+fn parse_bench_args(args: &[String]) -> Result<BenchArgs, String> {
+    let mut bench_args = BenchArgs {
+        days: vec![],
+        parts: vec![Part::One, Part::Two],
+        runs: 10,
+        json: None,
+        csv: None,
+        options: Options::default(),
+    };
+
+    let mut args_iter = args.iter();
+    match args_iter.next() {
+        Some(day) => bench_args.days = parse_day_arg(day)?,
+        None => return Err("missing day".to_string()),
+    }
+
+    while let Some(arg) = args_iter.next() {
+        match arg.as_str() {
+            "--part" => {
+                let part = args_iter.next().ok_or("missing value for --part")?;
+                let part = Part::try_from(part.as_str())?;
+                bench_args.parts = vec![part];
+            }
+            "--runs" => {
+                let runs = args_iter.next().ok_or("missing value for --runs")?;
+                bench_args.runs = runs.parse().ok().filter(|runs| *runs > 0).ok_or(format!("invalid runs '{}'", runs))?;
+            }
+            "--json" => {
+                let json = args_iter.next().ok_or("missing value for --json")?;
+                bench_args.json = Some(json.to_string());
+            }
+            "--csv" => {
+                let csv = args_iter.next().ok_or("missing value for --csv")?;
+                bench_args.csv = Some(csv.to_string());
+            }
+            "--threads" => bench_args.options.threads = Some(parse_threads(args_iter.next())?),
+            _ => return Err(format!("unknown argument '{}'", arg)),
+        }
+    }
+    Ok(bench_args)
+}
+
+fn write_report(path: &str, report: &str) -> bool {
+    match fs::write(path, report) {
+        Ok(_) => true,
+        Err(e) => {
+            eprintln!("cannot write {}: {}", path, e);
+            false
+        }
+    }
+}
+
+// time parse and the parts of every day over several runs
+fn bench(bench_args: &BenchArgs) -> bool {
+    let mut ok = true;
+    let mut benches = vec![];
+    for &day in bench_args.days.iter() {
+        let input = locate_input(day, None);
+        let lines = match input.read_lines() {
+            Ok(lines) => lines,
+            Err(e) => {
+                eprintln!("day {}: cannot read {}: {}", day, input, e);
+                ok = false;
+                continue;
+            }
+        };
+
+        match bench_day(day, &lines, &bench_args.parts, bench_args.runs, &bench_args.options).unwrap() {
+            Ok(bench) => {
+                println!("day {:>2} parse   {}", day, bench.parse);
+                for (part, stats) in bench.parts.iter() {
+                    println!("day {:>2} part {}  {}", day, part, stats);
+                }
+                benches.push(bench);
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                ok = false;
+            }
+        }
+    }
+
+    if let Some(path) = &bench_args.json {
+        ok &= write_report(path, &to_json(&benches));
+    }
+    if let Some(path) = &bench_args.csv {
+        ok &= write_report(path, &to_csv(&benches));
+    }
+    ok
+}
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    let result = match args.first().map(|s| s.as_str()) {
+        Some("run") => parse_run_args(&args[1..]).map(|run_args| run(&run_args)),
+        Some("bench") => parse_bench_args(&args[1..]).map(|bench_args| bench(&bench_args)),
+        Some("verify") => parse_verify_args(&args[1..]).map(|verify_args| verify(&verify_args)),
+        Some(command) => Err(format!("unknown command '{}'", command)),
+        None => Err("missing command".to_string()),
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
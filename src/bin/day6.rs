@@ -1,58 +1,27 @@
-use itertools::Itertools;
-use aoc_2022::read_lines_as_vec;
-
-fn part2(line: &String) -> u32 {
-    // 2472
-    let mut sum = 0u32;
-
-    let mut start = 0;
-    for i in 13..line.len() {
-        let chunk = line.get(start..i + 1).unwrap();
-        if chunk.chars().all_unique() {
-            sum = start as u32 + 14;
-            break;
+use aoc_2022::day6;
+use aoc_2022::input::locate_input;
+use std::env;
+use std::process::ExitCode;
+
+// optional argument: input file or - for stdin
+fn main() -> ExitCode {
+    let input = locate_input(6, env::args().nth(1).as_deref());
+    let lines = match input.read_lines() {
+        Ok(lines) => lines,
+        Err(e) => {
+            eprintln!("cannot read {}: {}", input, e);
+            return ExitCode::FAILURE;
         }
-
-        start += 1;
-    }
-    sum
-}
-
-
-fn part1(line: &String) -> u32 {
-    // 1034
-    let mut sum = 0u32;
-
-    let mut start = 0;
-    for i in 3..line.len() {
-        let chunk = line.get(start..i + 1).unwrap();
-        if chunk.chars().all_unique() {
-            sum = start as u32 + 4;
-            break;
+    };
+    let input = match day6::parse(&lines) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
         }
+    };
 
-        start += 1;
-    }
-    sum
-}
-fn main() {
-    let lines = read_lines_as_vec("input/input_day6.txt").unwrap();
-
-    // let lines = vec!["mjqjpqmgbljsphdztnvjfqwrcgsmlb"].iter().map(|s| s.to_string()).collect::<Vec<_>>();
-    println!("{}", part1(lines.first().unwrap()));
-    println!("{}", part2(lines.first().unwrap()));
-}
-#[cfg(test)]
-mod tests {
-    use crate::{part1, part2};
-
-    #[test]
-    fn it_works() {
-        let lines = vec!["mjqjpqmgbljsphdztnvjfqwrcgsmlb"].iter().map(|s| s.to_string()).collect::<Vec<_>>();
-
-        let result = part1(lines.first().unwrap());
-        assert_eq!(result, 7);
-        let result = part2(lines.first().unwrap());
-        assert_eq!(result, 19);
-    }
+    println!("{}", day6::part1(&input));
+    println!("{}", day6::part2(&input));
+    ExitCode::SUCCESS
 }
@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+
+// a sequence of states that starts to repeat itself
+// every time around the cycle the value grows by delta
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    // the first step that is part of the cycle
+    pub start: usize,
+    // steps until the state of start comes back
+    pub length: usize,
+    pub delta: i64,
+    // the value after 0, 1, .. start + length steps
+    pub values: Vec<i64>,
+}
+
+impl Cycle {
+    // the value after any number of steps, also far beyond the ones that were simulated
+    pub fn value_at(&self, step: u64) -> i64 {
+        let start = self.start as u64;
+        let length = self.length as u64;
+        if step < start + length {
+            return self.values[step as usize];
+        }
+        let rounds = (step - start) / length;
+        let rest = (step - start) % length;
+        self.values[(start + rest) as usize] + rounds as i64 * self.delta
+    }
+}
+
+impl Display for Cycle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "cycle of {} steps starting at step {}, the value grows by {} each time", self.length, self.start, self.delta)
+    }
+}
+
+// steps yields (state, value) after 0, 1, 2, .. steps
+// the state must hold everything that decides the following steps,
+// every state is remembered until one comes back
+// None if the steps run out before that
+pub fn find_cycle<S, I>(steps: I) -> Option<Cycle>
+where
+    S: Eq + Hash,
+    I: IntoIterator<Item = (S, i64)>,
+{
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut values = vec![];
+    for (step, (state, value)) in steps.into_iter().enumerate() {
+        values.push(value);
+        if let Some(start) = seen.insert(state, step) {
+            return Some(Cycle {
+                start,
+                length: step - start,
+                delta: value - values[start],
+                values,
+            });
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::find_cycle;
+
+    #[test]
+    fn it_works() {
+        // states 10, 11, 12 and then 3, 0, 1, 2, 3, 0, ..
+        // the value is the sum of all states so far
+        let states = (0..).map(|i: i64| if i < 3 { i + 10 } else { i % 4 });
+        let steps = states.scan(0, |sum, state| {
+            *sum += state;
+            Some((state, *sum))
+        });
+        let cycle = find_cycle(steps).unwrap();
+        assert_eq!((cycle.start, cycle.length, cycle.delta), (3, 4, 6));
+        assert_eq!(cycle.value_at(2), 33);
+        assert_eq!(cycle.value_at(9), 43);
+        assert_eq!(cycle.value_at(1_000_000_000_000_003), 33 + 6 * 250_000_000_000_000 + 3);
+        assert_eq!(cycle.to_string(), "cycle of 4 steps starting at step 3, the value grows by 6 each time");
+
+        assert!(find_cycle((0..10).map(|i| (i, i))).is_none());
+    }
+}
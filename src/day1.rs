@@ -0,0 +1,100 @@
+use crate::solver::Solver;
+use crate::error::{parse_number, Error};
+
+const DAY: usize = 1;
+
+#[derive(Debug, Clone)]
+pub struct Elves {
+    pub calories: Vec<Vec<u32>>,
+}
+
+pub fn parse(lines: &[String]) -> Result<Elves, Error> {
+    let mut elves = Elves {
+        calories: vec![],
+    };
+    let mut elve = vec![];
+
+    for (y, line) in lines.iter().enumerate() {
+        if !line.is_empty() {
+            let cal: u32 = parse_number(DAY, y, line)?;
+            elve.push(cal)
+        } else if !elve.is_empty() {
+            elves.calories.push(elve);
+            elve = vec![];
+        }
+    }
+    if !elve.is_empty() {
+        elves.calories.push(elve);
+    }
+    Ok(elves)
+}
+
+pub fn part2(elves: &Elves) -> Result<u32, String> {
+    // 204610
+    if elves.calories.len() < 3 {
+        return Err(format!("expected at least 3 elves but got {}", elves.calories.len()));
+    }
+    let mut sum_list = elves.calories.iter().map(|elve| elve.iter().sum::<u32>()).collect::<Vec<_>>();
+
+    sum_list.sort();
+    sum_list.reverse();
+    Ok(sum_list[0..3].iter().sum::<u32>())
+}
+
+pub fn part1(elves: &Elves) -> u32 {
+    // 70374
+    elves.calories.iter().map(|elve| elve.iter().sum::<u32>()).max().unwrap_or(0)
+}
+
+pub struct Day1;
+
+impl Solver for Day1 {
+    type Input = Elves;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, Error> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, Error> {
+        Ok(part1(input).to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, Error> {
+        part2(input).map(|sum| sum.to_string()).map_err(|e| Error::input(DAY, &e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let lines = vec!["1000",
+                         "2000",
+                         "3000",
+                         "",
+                         "4000",
+                         "",
+                         "5000",
+                         "6000",
+                         "",
+                         "7000",
+                         "8000",
+                         "9000",
+                         "",
+                         "10000"].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let input = parse(&lines).unwrap();
+
+        let result = part1(&input);
+        assert_eq!(result, 24000);
+        let result = part2(&input);
+        assert_eq!(result, Ok(45000));
+
+        // enough for part1 but not for part2
+        let lines = ["1000", "", "2000", ""].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let input = parse(&lines).unwrap();
+        assert_eq!(part1(&input), 2000);
+        assert_eq!(Day1::part2(&input).unwrap_err().to_string(), "day 1: expected at least 3 elves but got 2");
+    }
+}
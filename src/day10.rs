@@ -0,0 +1,195 @@
+use crate::solver::Solver;
+use crate::error::{get_field, parse_number, Error};
+
+const DAY: usize = 10;
+
+#[derive(Debug, Copy, Clone)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+pub fn parse(lines: &[String]) -> Result<Vec<Instruction>, Error> {
+    let mut instructions = vec![];
+    for (y, line) in lines.iter().enumerate() {
+        let fields = line.split_whitespace().collect::<Vec<_>>();
+        match fields.first() {
+            Some(&"addx") => {
+                let x_value: i32 = parse_number(DAY, y, get_field(DAY, y, &fields, 1)?)?;
+                instructions.push(Instruction::Addx(x_value));
+            }
+            Some(&"noop") => instructions.push(Instruction::Noop),
+            Some(other) => return Err(Error::parse(DAY, y, &format!("unknown instruction '{}'", other))),
+            None => {}
+        }
+    }
+    Ok(instructions)
+}
+
+// the letters of the crt, 4 pixels wide and 6 high, rows from top to bottom
+const LETTERS: [(char, [&str; 6]); 16] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+// the text on the screen, None if a letter is not known
+pub fn read_letters(screen: &str) -> Option<String> {
+    let rows = screen.lines().map(|row| row.chars().collect::<Vec<_>>()).collect::<Vec<_>>();
+    if rows.len() != 6 {
+        return None;
+    }
+    // every letter is followed by one dark column
+    let width = rows[0].len();
+    (0..width.div_ceil(5))
+        .map(|idx| {
+            let glyph = rows.iter().map(|row| {
+                (0..4).map(|x| if row.get(idx * 5 + x) == Some(&'#') { '#' } else { '.' }).collect::<String>()
+            }).collect::<Vec<_>>();
+            LETTERS.iter().find(|(_, pixels)| *pixels == glyph[..]).map(|(c, _)| *c)
+        })
+        .collect()
+}
+
+fn draw_crt(crt: Vec<Vec<u8>>) -> String {
+    let mut screen = String::new();
+    for y in 0..6 {
+        for x in 0..40 {
+            let c = crt.get(y).unwrap().get(x).unwrap();
+            if *c == 0 {
+                screen.push(' ')
+            } else {
+                screen.push('#')
+            }
+        }
+        if y != 5 {
+            screen.push('\n')
+        }
+    }
+    screen
+}
+
+fn cyle_to_crt_pos(cycle: i32) -> (i32, i32) {
+    // 1 -> 0,0
+    // 240 -> 39,5
+    let line = (cycle - 1) / 40;
+    ((cycle - 1) - (line * 40), line)
+}
+
+fn set_lit_pixel(crt: &mut [Vec<u8>], pos: (i32, i32)) {
+    crt[pos.1 as usize][pos.0 as usize] = 1
+}
+
+fn draw_pixel_if_needed(crt: &mut [Vec<u8>], x: i32, cycle: i32) {
+    let pos = cyle_to_crt_pos(cycle);
+    let screen_x = pos.0;
+    if screen_x >= x - 1 && screen_x <= x + 1 {
+        set_lit_pixel(crt, pos);
+    }
+}
+
+pub fn part2(instructions: &[Instruction]) -> String {
+    // BRJLFULP
+    let mut crt: Vec<Vec<u8>> = vec![vec![0; 40]; 6];
+    let mut cycle = 1i32;
+    let mut x = 1i32;
+
+    for instruction in instructions.iter() {
+        if let Instruction::Addx(x_value) = instruction {
+            for _ in 0..2 {
+                draw_pixel_if_needed(&mut crt, x, cycle);
+                cycle += 1;
+            }
+            x += *x_value;
+            draw_pixel_if_needed(&mut crt, x, cycle);
+        } else {
+            draw_pixel_if_needed(&mut crt, x, cycle);
+            cycle += 1;
+        }
+    }
+    draw_crt(crt)
+}
+
+pub fn part1(instructions: &[Instruction]) -> i32 {
+    // 12980
+    let mut x = 1i32;
+    let mut cycle = 0i32;
+    let mut num = 0i32;
+    let checkpoints = [20i32, 60i32, 100i32, 140i32, 180i32, 220i32];
+    for instruction in instructions.iter() {
+        if let Instruction::Addx(x_value) = instruction {
+            for _ in 0..2 {
+                cycle += 1;
+                if checkpoints.contains(&cycle) {
+                    num += cycle * x;
+                }
+            }
+            x += *x_value;
+        } else {
+            cycle += 1;
+            if checkpoints.contains(&cycle) {
+                num += cycle * x;
+            }
+        }
+    }
+    num
+}
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Input = Vec<Instruction>;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, Error> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, Error> {
+        Ok(part1(input).to_string())
+    }
+
+    // the letters if they can be read, so that they can be checked against the answers
+    fn part2(input: &Self::Input) -> Result<String, Error> {
+        let screen = part2(input);
+        Ok(read_letters(&screen).unwrap_or(screen))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2, read_letters};
+    use crate::read_lines_as_vec;
+
+    #[test]
+    fn it_works() {
+        let lines = read_lines_as_vec("input_test/input_day10_test.txt").unwrap();
+
+        let input = parse(&lines).unwrap();
+
+        let result = part1(&input);
+        assert_eq!(result, 13140);
+        // the example only draws stripes
+        assert_eq!(read_letters(&part2(&input)), None);
+
+        let screen = ["###  #  # ",
+                      "#  # #  # ",
+                      "###  #  # ",
+                      "#  # #  # ",
+                      "#  # #  # ",
+                      "###   ##  "].join("\n");
+        assert_eq!(read_letters(&screen), Some("BU".to_string()));
+    }
+}
@@ -0,0 +1,262 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use itertools::Itertools;
+use crate::solver::Solver;
+use crate::error::{parse_number, Error};
+
+const DAY: usize = 11;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Operand {
+    Old,
+    Value(u64),
+}
+
+// new = left op right
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Operation {
+    Add(Operand, Operand),
+    Mul(Operand, Operand),
+}
+
+impl Operand {
+    fn value(&self, old: u64) -> u64 {
+        match self {
+            Operand::Old => old,
+            Operand::Value(v) => *v,
+        }
+    }
+}
+
+impl Operation {
+    pub fn apply(&self, old: u64) -> u64 {
+        match self {
+            Operation::Add(left, right) => left.value(old) + right.value(old),
+            Operation::Mul(left, right) => left.value(old) * right.value(old),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    pub stack: VecDeque<u64>,
+    pub operation: Operation,
+    pub divisor: u64,
+    pub monkey_true_idx: usize,
+    pub monkey_false_idx: usize,
+    pub inspect_count: u64,
+}
+
+impl Monkey {
+    fn add_item(&mut self, item: u64) {
+        self.stack.push_back(item)
+    }
+
+    fn inspect_item2(&mut self, monkey_map: &HashMap<usize, RefCell<Monkey>>, supermodulo: u64) -> bool {
+        if !self.stack.is_empty() {
+            let monkey_true = &mut *monkey_map.get(&self.monkey_true_idx).unwrap().borrow_mut();
+            let monkey_false = &mut *monkey_map.get(&self.monkey_false_idx).unwrap().borrow_mut();
+            let item = self.stack.pop_front().unwrap();
+            let item_new = self.operation.apply(item);
+            let item_bored = if supermodulo != 0 { item_new % supermodulo } else { item_new / 3 };
+
+            if item_bored % self.divisor == 0 {
+                monkey_true.add_item(item_bored)
+            } else {
+                monkey_false.add_item(item_bored)
+            }
+            self.inspect_count += 1;
+
+            return true;
+        }
+        false
+
+    }
+}
+
+fn parse_operand(y: usize, operand: &str) -> Result<Operand, Error> {
+    if operand == "old" {
+        Ok(Operand::Old)
+    } else {
+        Ok(Operand::Value(parse_number(DAY, y, operand)?))
+    }
+}
+
+// the part after a fixed prefix like "  Test: divisible by"
+fn strip_line<'a>(lines: &'a [String], y: usize, prefix: &str) -> Result<&'a str, Error> {
+    let line = lines.get(y).ok_or_else(|| Error::input(DAY, &format!("missing '{}'", prefix.trim())))?;
+    line.trim()
+        .strip_prefix(prefix.trim())
+        .map(|rest| rest.trim())
+        .ok_or_else(|| Error::parse(DAY, y, &format!("expected '{}'", prefix.trim())))
+}
+
+// Monkey 0:
+//   Starting items: 79, 98
+//   Operation: new = old * 19
+//   Test: divisible by 23
+//     If true: throw to monkey 2
+//     If false: throw to monkey 3
+fn parse_monkey(lines: &[String], y: usize, idx: usize) -> Result<Monkey, Error> {
+    let id: usize = parse_number(DAY, y, strip_line(lines, y, "Monkey")?.trim_end_matches(":"))?;
+    if id != idx {
+        return Err(Error::parse(DAY, y, &format!("expected monkey {} but found monkey {}", idx, id)));
+    }
+
+    let mut stack = VecDeque::new();
+    let items = strip_line(lines, y + 1, "Starting items:")?;
+    for item in items.split(",").filter(|i| !i.trim().is_empty()) {
+        stack.push_back(parse_number(DAY, y + 1, item)?);
+    }
+
+    let expression = strip_line(lines, y + 2, "Operation: new =")?.split_whitespace().collect::<Vec<_>>();
+    if expression.len() != 3 {
+        return Err(Error::parse(DAY, y + 2, "expected 'new = a op b'"));
+    }
+    let left = parse_operand(y + 2, expression[0])?;
+    let right = parse_operand(y + 2, expression[2])?;
+    let operation = match expression[1] {
+        "+" => Operation::Add(left, right),
+        "*" => Operation::Mul(left, right),
+        op => return Err(Error::parse(DAY, y + 2, &format!("unknown operation '{}'", op))),
+    };
+
+    let divisor: u64 = parse_number(DAY, y + 3, strip_line(lines, y + 3, "Test: divisible by")?)?;
+    if divisor == 0 {
+        return Err(Error::parse(DAY, y + 3, "divisible by 0"));
+    }
+    let monkey_true_idx = parse_number(DAY, y + 4, strip_line(lines, y + 4, "If true: throw to monkey")?)?;
+    let monkey_false_idx = parse_number(DAY, y + 5, strip_line(lines, y + 5, "If false: throw to monkey")?)?;
+
+    Ok(Monkey {
+        stack,
+        operation,
+        divisor,
+        monkey_true_idx,
+        monkey_false_idx,
+        inspect_count: 0,
+    })
+}
+
+pub fn parse(lines: &[String]) -> Result<Vec<Monkey>, Error> {
+    let mut monkeys = vec![];
+
+    let mut y = 0;
+    while y < lines.len() {
+        if lines[y].trim().is_empty() {
+            y += 1;
+            continue;
+        }
+        monkeys.push(parse_monkey(lines, y, monkeys.len())?);
+        y += 6;
+    }
+
+    if monkeys.len() < 2 {
+        return Err(Error::input(DAY, "at least 2 monkeys are needed"));
+    }
+    for (idx, m) in monkeys.iter().enumerate() {
+        for target in [m.monkey_true_idx, m.monkey_false_idx] {
+            if target >= monkeys.len() || target == idx {
+                return Err(Error::input(DAY, &format!("monkey {} can not throw to monkey {}", idx, target)));
+            }
+        }
+        // inspect_item2 borrows both targets at once
+        if m.monkey_true_idx == m.monkey_false_idx {
+            return Err(Error::input(DAY, &format!("monkey {} throws to monkey {} either way", idx, m.monkey_true_idx)));
+        }
+    }
+    Ok(monkeys)
+}
+
+fn to_monkey_map(monkeys: &[Monkey]) -> HashMap<usize, RefCell<Monkey>> {
+    let mut monkey_map: HashMap<usize, RefCell<Monkey>> = HashMap::new();
+    for (idx, monkey) in monkeys.iter().enumerate() {
+        monkey_map.insert(idx, RefCell::new(monkey.clone()));
+    }
+    monkey_map
+}
+
+pub fn part1(monkeys: &[Monkey]) -> u64 {
+    // 66124
+    let monkey_map = to_monkey_map(monkeys);
+
+    for _ in 0..20 {
+        monkey_map.keys().sorted().for_each(|idx| {
+            let mut m = monkey_map.get(idx).unwrap().borrow_mut();
+            while m.inspect_item2(&monkey_map, 0) {}
+        });
+    }
+
+    let mut inspect_count = vec![];
+    monkey_map.values().for_each(|m| inspect_count.push(m.borrow().inspect_count));
+
+    inspect_count.sort();
+    inspect_count.reverse();
+    inspect_count.get(0..2).unwrap().iter().product::<u64>()
+}
+
+// https://brilliant.org/wiki/chinese-remainder-theorem/
+pub fn part2(monkeys: &[Monkey]) -> u64 {
+    // 19309892877
+    let monkey_map = to_monkey_map(monkeys);
+    let test_values = monkeys.iter().map(|m| m.divisor).collect::<Vec<_>>();
+
+    let supermodulo = test_values.iter().product();
+
+    for _ in 0..10000 {
+        monkey_map.keys().sorted().for_each(|idx| {
+            let mut m = monkey_map.get(idx).unwrap().borrow_mut();
+            while m.inspect_item2(&monkey_map, supermodulo) {}
+        });
+    }
+
+    let mut inspect_count = vec![];
+    monkey_map.values().for_each(|m| inspect_count.push(m.borrow().inspect_count));
+
+    inspect_count.sort();
+    inspect_count.reverse();
+    inspect_count.get(0..2).unwrap().iter().product::<u64>()
+}
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    type Input = Vec<Monkey>;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, Error> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, Error> {
+        Ok(part1(input).to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, Error> {
+        Ok(part2(input).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+    use crate::read_lines_as_vec;
+
+    #[test]
+    fn it_works() {
+        let lines = read_lines_as_vec("input_test/input_day11_test.txt").unwrap();
+
+        let input = parse(&lines).unwrap();
+
+        let result = part1(&input);
+        assert_eq!(result, 10605);
+        let result = part2(&input);
+        assert_eq!(result, 2713310158);
+
+        let mut same_target = lines.clone();
+        same_target[5] = "    If false: throw to monkey 2".to_string();
+        assert_eq!(parse(&same_target).unwrap_err().to_string(), "day 11: monkey 0 throws to monkey 2 either way");
+        let mut to_itself = lines.clone();
+        to_itself[4] = "    If true: throw to monkey 0".to_string();
+        assert_eq!(parse(&to_itself).unwrap_err().to_string(), "day 11: monkey 0 can not throw to monkey 0");
+    }
+}
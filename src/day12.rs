@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+use crate::solver::Solver;
+use crate::error::Error;
+use crate::grid::{Grid, Pos};
+use crate::search::{bfs, bfs_reach};
+
+const DAY: usize = 12;
+
+fn get_height(c: char) -> usize {
+    c.to_ascii_lowercase() as usize - 96
+}
+
+fn get_next_possible_pos2(grid: &Grid<usize>, current_height: usize, pos: Pos) -> Vec<Pos> {
+    let candidates = grid.neighbours4(pos);
+
+    let mut pos_list = vec![];
+    for next_pos in candidates {
+        let new_height = *grid.get(next_pos).unwrap();
+        if new_height > current_height || new_height == current_height - 1 || new_height == current_height {
+            pos_list.push(next_pos);
+        }
+    }
+    pos_list
+}
+
+fn get_next_possible_pos(grid: &Grid<usize>, current_height: usize, pos: Pos) -> Vec<Pos> {
+    let candidates = grid.neighbours4(pos);
+
+    let mut pos_list = vec![];
+    for next_pos in candidates {
+        let new_height = *grid.get(next_pos).unwrap();
+        if new_height <= current_height + 1 {
+            pos_list.push(next_pos);
+        }
+    }
+    pos_list
+}
+
+// the squares from S to E
+pub fn route_up(map: &Heightmap) -> Option<Vec<Pos>> {
+    let grid = &map.grid;
+    bfs(map.start, |&pos| get_next_possible_pos(grid, *grid.get(pos).unwrap(), pos), |&pos| pos == map.end)
+        .map(|path| path.nodes)
+}
+
+// the squares from the closest lowest square to E
+// searched from E down to the first square with the lowest height
+pub fn route_down(map: &Heightmap) -> Option<Vec<Pos>> {
+    let grid = &map.grid;
+    let lowest = get_height('a');
+    bfs(map.end, |&pos| get_next_possible_pos2(grid, *grid.get(pos).unwrap(), pos), |&pos| *grid.get(pos).unwrap() == lowest)
+        .map(|path| path.nodes.into_iter().rev().collect())
+}
+
+// the steps from S to every square that can be reached
+pub fn distances(map: &Heightmap) -> HashMap<Pos, usize> {
+    let grid = &map.grid;
+    bfs_reach(map.start, |&pos| get_next_possible_pos(grid, *grid.get(pos).unwrap(), pos))
+}
+
+fn get_char(map: &Heightmap, pos: Pos) -> char {
+    if pos == map.start {
+        'S'
+    } else if pos == map.end {
+        'E'
+    } else {
+        (*map.grid.get(pos).unwrap() as u8 + 96) as char
+    }
+}
+
+// the route drawn with arrows like in the puzzle text
+pub fn render_route(map: &Heightmap, route: &[Pos]) -> String {
+    let mut arrows: Grid<char> = Grid::new(map.grid.width(), map.grid.height(), '.');
+    for (from, to) in route.iter().zip(route.iter().skip(1)) {
+        let arrow = match (to.0 - from.0, to.1 - from.1) {
+            (1, 0) => '>',
+            (-1, 0) => '<',
+            (0, 1) => 'v',
+            _ => '^',
+        };
+        arrows.set(*from, arrow);
+    }
+    if let Some(last) = route.last() {
+        arrows.set(*last, 'E');
+    }
+    arrows.to_string()
+}
+
+// the heightmap with the distance from S as background color, blue is close and red is far
+// squares that can not be reached keep the terminal colors
+pub fn render_heatmap(map: &Heightmap, distances: &HashMap<Pos, usize>) -> String {
+    let max_distance = distances.values().max().copied().unwrap_or(0).max(1);
+    let mut out = String::new();
+    for y in 0..map.grid.height() as i32 {
+        for (pos, _) in map.grid.row(y) {
+            let c = get_char(map, pos);
+            match distances.get(&pos) {
+                Some(d) => {
+                    // 6x6x6 color cube of the 256 color palette
+                    let red = d * 5 / max_distance;
+                    let color = 16 + 36 * red + (5 - red);
+                    out += &format!("\x1b[48;5;{}m{}", color, c);
+                }
+                None => {
+                    out += &format!("\x1b[0m{}", c);
+                }
+            }
+        }
+        out += "\x1b[0m\n";
+    }
+    out
+}
+
+#[derive(Debug, Clone)]
+pub struct Heightmap {
+    pub grid: Grid<usize>,
+    pub start: Pos,
+    pub end: Pos,
+}
+
+pub fn parse(lines: &[String]) -> Result<Heightmap, Error> {
+    let mut start = None;
+    let mut end = None;
+    let grid = Grid::parse(DAY, lines, |pos, c| {
+        if c == 'S' {
+            start = Some(pos);
+            Some(get_height('a'))
+        } else if c == 'E' {
+            end = Some(pos);
+            Some(get_height('z'))
+        } else if c.is_ascii_lowercase() {
+            Some(get_height(c))
+        } else {
+            None
+        }
+    })?;
+    let start = start.ok_or_else(|| Error::input(DAY, "no start 'S' found"))?;
+    let end = end.ok_or_else(|| Error::input(DAY, "no end 'E' found"))?;
+    Ok(Heightmap { grid, start, end })
+}
+
+pub fn part1(map: &Heightmap) -> Result<usize, String> {
+    // 447
+    route_up(map).map(|route| route.len() - 1).ok_or_else(|| "E can not be reached from S".to_string())
+}
+
+pub fn part2(map: &Heightmap) -> Result<usize, String> {
+    // 446
+    route_down(map).map(|route| route.len() - 1).ok_or_else(|| "E can not be reached from any a".to_string())
+}
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    type Input = Heightmap;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, Error> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, Error> {
+        part1(input).map(|steps| steps.to_string()).map_err(|e| Error::input(DAY, &e))
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, Error> {
+        part2(input).map(|steps| steps.to_string()).map_err(|e| Error::input(DAY, &e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solver::Solver;
+    use super::{parse, part1, part2, render_route, route_down, route_up, Day12};
+
+    #[test]
+    fn it_works() {
+        let lines = ["Sabqponm",
+                         "abcryxxl",
+                         "accszExk",
+                         "acctuvwj",
+                         "abdefghi"].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        let input = parse(&lines).unwrap();
+
+        let result = part1(&input);
+        assert_eq!(result, Ok(31));
+        let result = part2(&input);
+        assert_eq!(result, Ok(29));
+
+        let route = route_up(&input).unwrap();
+        assert_eq!(route.first(), Some(&input.start));
+        assert_eq!(route.last(), Some(&input.end));
+        assert_eq!(render_route(&input, &route), [">>vv<<<<",
+                                                  "..vvv<<^",
+                                                  "..vv>E^^",
+                                                  "..v>>>^^",
+                                                  "..>>>>>^",
+                                                  ""].join("\n"));
+        let route = route_down(&input).unwrap();
+        assert_eq!(route.len(), 30);
+        assert_eq!(route.last(), Some(&input.end));
+
+        // the only way to E is too steep
+        let input = parse(&["SacE".to_string()]).unwrap();
+        assert_eq!(Day12::part1(&input).unwrap_err().to_string(), "day 12: E can not be reached from S");
+        assert_eq!(part2(&input), Err("E can not be reached from any a".to_string()));
+    }
+}
@@ -0,0 +1,256 @@
+use regex::Regex;
+use std::cmp::Ordering;
+use crate::solver::Solver;
+use crate::error::Error;
+
+const DAY: usize = 13;
+
+fn get_sublist(string: &str) -> Vec<String> {
+    let mut idx = 0;
+    let mut l = vec![];
+    while idx < string.len() {
+        if let Some(left) = string[idx..].find("[") {
+            let start = idx + left;
+            if start != idx {
+                l.push(string[idx..start - 1].to_string());
+            }
+            let mut lvl = 0;
+            let mut end = 0;
+            for (i, c) in string.get(start + 1..).unwrap().chars().enumerate() {
+                if c == '[' {
+                    lvl += 1
+                }
+                if c == ']' {
+                    if lvl == 0 {
+                        end = start + i + 1;
+                        break;
+                    } else {
+                        lvl -= 1;
+                    }
+                }
+            }
+            l.push(string[start..end + 1].to_string());
+            idx = end + 1;
+            if string.starts_with(",") {
+                idx += 1;
+            }
+        } else {
+            l.push(string[idx..].to_string());
+            break;
+        }
+    }
+    l
+}
+
+#[derive(Debug, Eq)]
+pub enum Element {
+    Integer(i32),
+    List(Box<List>),
+}
+
+#[derive(Debug, Eq)]
+pub struct List {
+    pub items: Vec<Element>,
+}
+
+#[derive(Debug)]
+pub struct PacketPair {
+    pub left: List,
+    pub right: List,
+}
+
+impl PartialEq for Element {
+    fn eq(&self, other: &Element) -> bool {
+        match (self, other) {
+            (Element::Integer(a), Element::Integer(b)) => a == b,
+            (Element::List(a), Element::List(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq for List {
+    fn eq(&self, other: &List) -> bool {
+        self.items == other.items
+    }
+}
+
+
+impl Ord for List {
+    fn cmp(&self, other: &List) -> Ordering {
+        self.items.cmp(&other.items)
+    }
+}
+
+impl PartialOrd for List {
+    fn partial_cmp(&self, other: &List) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Element {
+    fn cmp(&self, other: &Element) -> Ordering {
+        match (self, other) {
+            (Element::Integer(a), Element::Integer(b)) => a.cmp(b),
+            (Element::List(a), Element::List(b)) => a.cmp(b),
+            (Element::List(a), Element::Integer(b)) => a.as_ref().cmp(&List { items: vec![Element::Integer(*b)] }),
+            (Element::Integer(a), Element::List(b)) => List { items: vec![Element::Integer(*a)] }.cmp(b),
+        }
+    }
+}
+
+impl PartialOrd for Element {
+    fn partial_cmp(&self, other: &Element) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn split_lists(string: &str, root: &mut List) {
+    let re = Regex::new(r"\d+").unwrap();
+    let l = get_sublist(string);
+
+    for part in l {
+        if part.starts_with("[") {
+            let mut l = List {
+                items: vec![],
+            };
+            split_lists(&part[1..part.len() - 1], &mut l);
+            root.items.push(Element::List(Box::new(l)));
+        } else {
+            let parts = part.split(",").collect::<Vec<&str>>();
+            for p in parts {
+                if !p.is_empty() && p.chars().next().unwrap().is_ascii_digit() {
+                    let digits = re.find_iter(p).collect::<Vec<_>>();
+                    let num: i32 = digits.first().unwrap().as_str().parse().unwrap();
+                    let int_value = Element::Integer(num);
+                    root.items.push(int_value);
+                }
+            }
+        }
+    }
+}
+
+fn parse_packet(packet: &str) -> List {
+    let mut root = List {
+        items: vec![],
+    };
+    split_lists(&packet[1..packet.len() - 1], &mut root);
+    root
+}
+
+// split_lists expects a well formed packet - so check that first
+fn validate_packet(y: usize, packet: &str) -> Result<(), Error> {
+    if !packet.starts_with('[') {
+        return Err(Error::parse(DAY, y, "packet must start with '['"));
+    }
+    let mut lvl = 0;
+    for (x, c) in packet.chars().enumerate() {
+        match c {
+            '[' => lvl += 1,
+            ']' => {
+                if lvl == 0 {
+                    return Err(Error::unexpected_char(DAY, y, x, c));
+                }
+                lvl -= 1;
+                if lvl == 0 && x != packet.len() - 1 {
+                    return Err(Error::parse(DAY, y, "data after the end of the packet"));
+                }
+            }
+            ',' | '0'..='9' => {}
+            _ => return Err(Error::unexpected_char(DAY, y, x, c)),
+        }
+    }
+    if lvl != 0 {
+        return Err(Error::parse(DAY, y, "missing ']'"));
+    }
+    Ok(())
+}
+
+pub fn parse(lines: &[String]) -> Result<Vec<PacketPair>, Error> {
+    let mut pairs = vec![];
+    for (pair_idx, pair) in lines.chunks(3).enumerate() {
+        let y = pair_idx * 3;
+        if pair.len() < 2 {
+            return Err(Error::parse(DAY, y, "packet without a partner"));
+        }
+        validate_packet(y, pair[0].trim())?;
+        validate_packet(y + 1, pair[1].trim())?;
+        if pair.len() == 3 && !pair[2].is_empty() {
+            return Err(Error::parse(DAY, y + 2, "expected an empty line between pairs"));
+        }
+        let left = parse_packet(pair[0].trim());
+        let right = parse_packet(pair[1].trim());
+        pairs.push(PacketPair { left, right });
+    }
+    Ok(pairs)
+}
+
+pub fn part1(pairs: &[PacketPair]) -> u32 {
+    // 5555
+    let mut sum = 0u32;
+    for (pair_idx, pair) in (1..).zip(pairs.iter()) {
+        if pair.left < pair.right {
+            // println!("{}", pair_idx);
+            sum += pair_idx
+        }
+    }
+    sum
+}
+
+pub fn part2(pairs: &[PacketPair]) -> u32 {
+    // 22852
+    let decoder_key_1 = parse_packet("[[2]]");
+    let decoder_key_2 = parse_packet("[[6]]");
+
+    let mut signal_lists: Vec<&List> = vec![];
+    for pair in pairs {
+        signal_lists.push(&pair.left);
+        signal_lists.push(&pair.right);
+    }
+    signal_lists.push(&decoder_key_1);
+    signal_lists.push(&decoder_key_2);
+    signal_lists.sort();
+
+    let a = signal_lists.iter().position(|x| x.eq(&&decoder_key_1)).unwrap();
+    let b = signal_lists.iter().position(|x| x.eq(&&decoder_key_2)).unwrap();
+
+    ((a + 1) * (b + 1)) as u32
+}
+
+// https://maebli.github.io/rust/2023/01/22/100rust-75.html
+
+pub struct Day13;
+
+impl Solver for Day13 {
+    type Input = Vec<PacketPair>;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, Error> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, Error> {
+        Ok(part1(input).to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, Error> {
+        Ok(part2(input).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+    use crate::read_lines_as_vec;
+
+    #[test]
+    fn it_works() {
+        let lines = read_lines_as_vec("input_test/input_day13_test.txt").unwrap();
+
+        let input = parse(&lines).unwrap();
+
+        let result = part1(&input);
+        assert_eq!(result, 13);
+        let result = part2(&input);
+        assert_eq!(result, 140);
+    }
+}
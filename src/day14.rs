@@ -0,0 +1,258 @@
+use std::cmp::max;
+use std::fmt::{Display, Formatter};
+use crate::solver::Solver;
+use crate::error::{parse_number, Error};
+use crate::grid::Grid;
+
+const DAY: usize = 14;
+
+#[allow(dead_code)]
+enum Tile {
+    Rock,
+    Air,
+    Sand,
+}
+
+#[derive(Debug, Clone)]
+pub struct RockPath {
+    pub points: Vec<(i32, i32)>,
+}
+
+struct Cave {
+    data: Grid<Tile>,
+    max_y: i32,
+}
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tile::Rock => { write!(f, "#") }
+            Tile::Air => { write!(f, ".") }
+            Tile::Sand => { write!(f, "o") }
+        }
+    }
+}
+
+fn mark_as_rock(cave: &mut Cave, start: (i32, i32), end: (i32, i32)) {
+    let mut start_x = [start.0, end.0];
+    start_x.sort();
+    for x in *start_x.first().unwrap()..*start_x.last().unwrap() + 1 {
+        let p = (x, start.1);
+        cave.data.set(p, Tile::Rock);
+    }
+    let mut start_y = [start.1, end.1];
+    start_y.sort();
+    for y in *start_y.first().unwrap()..*start_y.last().unwrap() + 1 {
+        let p = (start.0, y);
+        cave.data.set(p, Tile::Rock);
+    }
+}
+
+#[allow(dead_code)]
+fn print_grid(cave: &Cave) {
+    print!("{}", cave.data)
+}
+
+fn is_possible_move(cave: &Cave, pos: (i32, i32)) -> bool {
+    pos.1 <= cave.max_y
+}
+
+fn try_move_sand(cave: &mut Cave, pos: (i32, i32)) -> Option<(i32, i32)> {
+    let down = (pos.0, pos.1 + 1);
+    if !cave.data.contains(down) {
+        cave.data.remove(pos);
+        cave.data.set(down, Tile::Sand);
+        return Some(down);
+    }
+
+    let down_left = (pos.0 - 1, pos.1 + 1);
+    if !cave.data.contains(down_left) {
+        cave.data.remove(pos);
+        cave.data.set(down_left, Tile::Sand);
+        return Some(down_left);
+    }
+
+    let down_right = (pos.0 + 1, pos.1 + 1);
+    if !cave.data.contains(down_right) {
+        cave.data.remove(pos);
+        cave.data.set(down_right, Tile::Sand);
+        return Some(down_right);
+    }
+
+    None
+}
+
+fn try_move_sand_with_floor(cave: &mut Cave, pos: (i32, i32)) -> ((i32, i32), bool) {
+    let down = (pos.0, pos.1 + 1);
+    if !cave.data.contains(down)
+        && is_possible_move(cave, down) {
+            cave.data.remove(pos);
+            cave.data.set(down, Tile::Sand);
+            return (down, true);
+        }
+
+    let down_left = (pos.0 - 1, pos.1 + 1);
+    if !cave.data.contains(down_left)
+        && is_possible_move(cave, down) {
+            cave.data.remove(pos);
+            cave.data.set(down_left, Tile::Sand);
+            return (down_left, true);
+        }
+
+    let down_right = (pos.0 + 1, pos.1 + 1);
+    if !cave.data.contains(down_right)
+        && is_possible_move(cave, down) {
+            cave.data.remove(pos);
+            cave.data.set(down_right, Tile::Sand);
+            return (down_right, true);
+        }
+
+    (pos, false)
+}
+
+pub fn part1(paths: &[RockPath]) -> u32 {
+    // 1406
+    let mut cave = Cave {
+        data: Grid::sparse(),
+        max_y: 0,
+    };
+
+    build_grid(&mut cave, paths);
+
+    let start = (500, 0);
+    let mut overflow = false;
+    let mut count = 0;
+    while !overflow {
+        let mut sand_corn = start;
+
+        loop {
+            match try_move_sand(&mut cave, sand_corn) {
+                None => {
+                    count += 1;
+                    // print_grid(&cave);
+                    break;
+                }
+                Some(new_pos) => {
+                    sand_corn = new_pos;
+                    if new_pos.1 > cave.max_y {
+                        // print_grid(&cave);
+                        overflow = true;
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    // print_grid(&cave);
+    count
+}
+
+
+pub fn part2(paths: &[RockPath]) -> u32 {
+    // 20870
+    let mut cave = Cave {
+        data: Grid::sparse(),
+        max_y: 0,
+    };
+
+    build_grid(&mut cave, paths);
+
+    let start = (500, 0);
+    cave.max_y += 1;
+
+    // print_grid(&cave);
+    let mut overflow = false;
+    let mut count = 0;
+    while !overflow {
+        let mut sand_corn = start;
+
+        loop {
+            let (new_pos, moved) = try_move_sand_with_floor(&mut cave, sand_corn);
+            if !moved {
+                count += 1;
+
+                if new_pos == start {
+                    // print_grid(&cave);
+                    overflow = true;
+                }
+                break;
+            } else {
+                sand_corn = new_pos
+            }
+        }
+    }
+    count
+}
+
+pub fn parse(lines: &[String]) -> Result<Vec<RockPath>, Error> {
+    let mut paths = vec![];
+    for (line_idx, line) in lines.iter().enumerate() {
+        let mut points = vec![];
+        for ccords in line.split(" -> ") {
+            let pair = ccords.split(",").collect::<Vec<_>>();
+            if pair.len() != 2 {
+                return Err(Error::parse(DAY, line_idx, &format!("invalid point '{}'", ccords)));
+            }
+            let x: i32 = parse_number(DAY, line_idx, pair[0])?;
+            let y: i32 = parse_number(DAY, line_idx, pair[1])?;
+            points.push((x, y));
+        }
+        // only horizontal or vertical lines are possible
+        for (a, b) in points.iter().zip(points.iter().skip(1)) {
+            if a.0 != b.0 && a.1 != b.1 {
+                return Err(Error::parse(DAY, line_idx, &format!("diagonal rock path {:?} -> {:?}", a, b)));
+            }
+        }
+        paths.push(RockPath { points });
+    }
+    Ok(paths)
+}
+
+fn build_grid(cave: &mut Cave, paths: &[RockPath]) {
+    for path in paths.iter() {
+        let mut last: Option<(i32, i32)> = None;
+        for &(x, y) in path.points.iter() {
+            cave.max_y = max(cave.max_y, y);
+
+            if let Some(last) = last {
+                mark_as_rock(cave, last, (x, y))
+            }
+            last = Some((x, y))
+        }
+    }
+}
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    type Input = Vec<RockPath>;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, Error> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, Error> {
+        Ok(part1(input).to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, Error> {
+        Ok(part2(input).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+
+    #[test]
+    fn it_works() {
+        let lines = ["498,4 -> 498,6 -> 496,6",
+                         "503,4 -> 502,4 -> 502,9 -> 494,9"].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        let input = parse(&lines).unwrap();
+
+        let result = part1(&input);
+        assert_eq!(result, 24);
+        let result = part2(&input);
+        assert_eq!(result, 93);
+    }
+}
@@ -0,0 +1,342 @@
+use itertools::Itertools;
+use std::collections::HashMap;
+use regex::Regex;
+use crate::solver::Solver;
+use crate::error::{parse_number, Error};
+
+const DAY: usize = 16;
+// every set of them gets a slot in the tables of every agent, about 40 bytes per set
+// for two agents, so 2^20 sets take 40 MB and every valve more doubles that
+const MAX_FLOW_VALVES: usize = 20;
+// three or more agents try every split of every set, that is 3^n work
+const MAX_FLOW_VALVES_MANY_AGENTS: usize = 16;
+
+#[derive(Debug, Clone)]
+pub struct Graph {
+    pub nodes: HashMap<String, Valve>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Valve {
+    pub name: String,
+    pub cost: u32,
+    pub edges: Vec<Edge>,
+    pub id: u32,
+
+}
+
+#[derive(Debug, Clone)]
+pub struct Edge {
+    pub from: String,
+    pub to: String,
+}
+
+// how many agents open valves for how long
+// more than two agents only work with up to 16 valves that have a flow rate
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub agents: usize,
+    pub minutes: u32,
+}
+
+impl Default for Options {
+    // you alone like in part 1
+    fn default() -> Self {
+        Options { agents: 1, minutes: 30 }
+    }
+}
+
+// the valves every agent opens in order and the pressure they release together
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    pub pressure: u32,
+    pub orders: Vec<Vec<String>>,
+}
+
+pub fn part1(g: &Graph) -> u32 {
+    // 2320
+    // parse only lets through graphs one or two agents can handle
+    release_pressure(g, &Options::default()).unwrap().pressure
+}
+
+// all agents start at AA at the same time and never open the same valve
+pub fn release_pressure(g: &Graph, options: &Options) -> Result<Plan, String> {
+    let network = Network::new(g);
+    if options.agents > 2 && network.flows.len() > MAX_FLOW_VALVES_MANY_AGENTS {
+        return Err(format!("{} agents only work with up to {} valves with a flow rate but there are {}",
+                           options.agents, MAX_FLOW_VALVES_MANY_AGENTS, network.flows.len()));
+    }
+
+    // the best single agent flow for every set of open valves
+    let all = (1usize << network.flows.len()) - 1;
+    let mut single = vec![0; all + 1];
+    tsp_mod(&network, network.start(), options.minutes, 0, 0, &mut single);
+
+    // best[k][set] is the most k agents release with the valves of set
+    // and choice[k][set] the valves the last of them opens
+    let mut best = vec![vec![0u32; all + 1]];
+    let mut choice = vec![vec![0usize; all + 1]];
+    for k in 1..options.agents + 1 {
+        let mut best_k = vec![0u32; all + 1];
+        let mut choice_k = vec![0usize; all + 1];
+        if k == 1 && k < options.agents {
+            // one agent on its own, the best of all subsets spread one valve at a time
+            best_k.copy_from_slice(&single);
+            choice_k.iter_mut().enumerate().for_each(|(set, valves)| *valves = set);
+            for valve in 0..network.flows.len() {
+                for set in (0..all + 1).filter(|set| set & (1 << valve) != 0) {
+                    let without = set & !(1 << valve);
+                    if best_k[without] > best_k[set] {
+                        best_k[set] = best_k[without];
+                        choice_k[set] = choice_k[without];
+                    }
+                }
+            }
+            best.push(best_k);
+            choice.push(choice_k);
+            continue;
+        }
+        // the last agent only needs the set of all valves
+        let sets = if k == options.agents { all..all + 1 } else { 0..all + 1 };
+        for set in sets {
+            // every subset of set, including set and the empty one
+            let mut valves = set;
+            loop {
+                let flow = single[valves] + best[k - 1][set & !valves];
+                if flow > best_k[set] {
+                    best_k[set] = flow;
+                    choice_k[set] = valves;
+                }
+                if valves == 0 {
+                    break;
+                }
+                valves = (valves - 1) & set;
+            }
+        }
+        best.push(best_k);
+        choice.push(choice_k);
+    }
+
+    let mut orders = vec![];
+    let mut set = all;
+    for k in (1..options.agents + 1).rev() {
+        let valves = choice[k][set];
+        let mut order = (0, vec![]);
+        best_order(&network, network.start(), options.minutes, valves, 0, 0, &mut vec![], &mut order);
+        orders.push(order.1.iter().map(|valve| network.names[*valve].to_string()).collect());
+        set &= !valves;
+    }
+    Ok(Plan { pressure: best[options.agents][all], orders })
+}
+
+// the valves with flow numbered 0.. so that a set of them fits into the bits of a usize,
+// AA comes last
+struct Network {
+    names: Vec<String>,
+    flows: Vec<u32>,
+    // minutes to walk from one of them to another
+    dist: Vec<Vec<u32>>,
+}
+
+impl Network {
+    fn new(g: &Graph) -> Network {
+        let size = g.nodes.len();
+        let by_id = g.nodes.values().sorted_by_key(|v| v.id).collect::<Vec<_>>();
+
+        // floyd warshall over all valves, every tunnel takes one minute
+        // https://www.geeksforgeeks.org/floyd-warshall-algorithm-dp-16/
+        let mut d_matrix = vec![vec![u32::MAX; size]; size];
+        for v in by_id.iter() {
+            d_matrix[v.id as usize][v.id as usize] = 0;
+            for e in v.edges.iter() {
+                d_matrix[v.id as usize][g.nodes[&e.to].id as usize] = 1;
+            }
+        }
+        for k in 0..size {
+            for i in 0..size {
+                for j in 0..size {
+                    let d = d_matrix[i][k].saturating_add(d_matrix[k][j]);
+                    if d < d_matrix[i][j] {
+                        d_matrix[i][j] = d;
+                    }
+                }
+            }
+        }
+
+        let mut valves = by_id.iter().filter(|v| v.cost != 0).collect::<Vec<_>>();
+        let flows = valves.iter().map(|v| v.cost).collect();
+        valves.push(&by_id[g.nodes["AA"].id as usize]);
+        Network {
+            names: valves.iter().map(|v| v.name.to_string()).collect(),
+            flows,
+            dist: valves.iter().map(|from| valves.iter().map(|to| d_matrix[from.id as usize][to.id as usize]).collect()).collect(),
+        }
+    }
+
+    fn start(&self) -> usize {
+        self.names.len() - 1
+    }
+}
+
+fn get_valves_with_flow(g: &Graph) -> Vec<u32> {
+    g.nodes.values().filter(|x| x.cost != 0).map(|x| x.id).collect::<Vec<_>>()
+}
+
+// https://github.com/WinterCore/aoc2022/blob/main/day16/main.rs
+// traveling salesman dont visit all values but only the ones with rate != 0
+// https://www.geeksforgeeks.org/travelling-salesman-problem-using-dynamic-programming/
+// memo keeps the best flow for every set of open valves
+fn tsp_mod(network: &Network, current_valve: usize, minutes: u32, opened: usize, flow: u32, memo: &mut [u32]) {
+    memo[opened] = memo[opened].max(flow);
+
+    for valve in 0..network.flows.len() {
+        // distance is time in minutes
+        // plus 1 to open the valve
+        let cost = network.dist[current_valve][valve].saturating_add(1);
+
+        // already open or no time left
+        if opened & (1 << valve) != 0 || cost >= minutes {
+            continue;
+        }
+
+        let cur_minutes = minutes - cost;
+        let cur_flow = flow + cur_minutes * network.flows[valve];
+        tsp_mod(network, valve, cur_minutes, opened | 1 << valve, cur_flow, memo);
+    }
+}
+
+// the order to open some of the allowed valves in that releases the most
+// opened holds the valves of path
+#[allow(clippy::too_many_arguments)]
+fn best_order(network: &Network, current_valve: usize, minutes: u32, allowed: usize, opened: usize, flow: u32,
+              path: &mut Vec<usize>, best: &mut (u32, Vec<usize>)) {
+    if flow > best.0 {
+        *best = (flow, path.clone());
+    }
+    for valve in 0..network.flows.len() {
+        let cost = network.dist[current_valve][valve].saturating_add(1);
+        if allowed & !opened & (1 << valve) == 0 || cost >= minutes {
+            continue;
+        }
+        path.push(valve);
+        let flow = flow + (minutes - cost) * network.flows[valve];
+        best_order(network, valve, minutes - cost, allowed, opened | 1 << valve, flow, path, best);
+        path.pop();
+    }
+}
+
+pub fn parse(lines: &[String]) -> Result<Graph, Error> {
+    let mut g = Graph {
+        nodes: HashMap::new(),
+    };
+
+    // Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+    let line_re = Regex::new(r"^Valve ([A-Z][A-Z]) has flow rate=(\d+); tunnels? leads? to valves? ([A-Z][A-Z](, [A-Z][A-Z])*)$").unwrap();
+    for (valve_id, line) in (0..).zip(lines.iter()) {
+        let y = valve_id as usize;
+        let caps = line_re.captures(line.trim()).ok_or_else(|| Error::parse(DAY, y, "expected 'Valve XX has flow rate=N; tunnels lead to valves YY, ZZ'"))?;
+        let from_valve = caps.get(1).unwrap().as_str();
+        let to_values = caps[3].split(", ").collect::<Vec<_>>();
+        let cost: u32 = parse_number(DAY, y, &caps[2])?;
+        if g.nodes.contains_key(from_valve) {
+            return Err(Error::parse(DAY, y, &format!("duplicate valve {}", from_valve)));
+        }
+        let mut edges = vec![];
+        for to in to_values.iter() {
+            let e = Edge {
+                from: from_valve.to_string(),
+                to: to.to_string(),
+            };
+            edges.push(e);
+        }
+        let v = Valve {
+            name: from_valve.to_string(),
+            cost,
+            edges,
+            id: valve_id,
+        };
+        g.nodes.insert(v.name.to_string(), v.clone());
+    }
+    for v in g.nodes.values() {
+        for e in v.edges.iter() {
+            if !g.nodes.contains_key(&e.to) {
+                return Err(Error::input(DAY, &format!("tunnel from {} leads to unknown valve {}", e.from, e.to)));
+            }
+        }
+    }
+    if get_valves_with_flow(&g).len() > MAX_FLOW_VALVES {
+        return Err(Error::input(DAY, &format!("more than {} valves with a flow rate are not supported", MAX_FLOW_VALVES)));
+    }
+    if !g.nodes.contains_key("AA") {
+        return Err(Error::input(DAY, "no start valve AA"));
+    }
+    Ok(g)
+}
+
+pub fn part2(g: &Graph) -> u32 {
+    // 2967
+    release_pressure(g, &Options { agents: 2, minutes: 26 }).unwrap().pressure
+}
+
+pub struct Day16;
+
+impl Solver for Day16 {
+    type Input = Graph;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, Error> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, Error> {
+        Ok(part1(input).to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, Error> {
+        Ok(part2(input).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2, release_pressure, Options};
+    use crate::read_lines_as_vec;
+
+    #[test]
+    fn it_works() {
+        let lines = read_lines_as_vec("input_test/input_day16_test.txt").unwrap();
+
+        let input = parse(&lines).unwrap();
+
+        let result = part1(&input);
+        assert_eq!(result, 1651);
+        let result = part2(&input);
+        assert_eq!(result, 1707);
+
+        let plan = release_pressure(&input, &Options::default()).unwrap();
+        assert_eq!(plan.orders, vec![vec!["DD", "BB", "JJ", "HH", "EE", "CC"]]);
+        let plan = release_pressure(&input, &Options { agents: 2, minutes: 26 }).unwrap();
+        assert_eq!(plan.orders.iter().map(|order| order.len()).sum::<usize>(), 6);
+        let plan = release_pressure(&input, &Options { agents: 3, minutes: 26 }).unwrap();
+        assert!(plan.pressure >= 1707);
+        assert_eq!(plan.orders.len(), 3);
+        assert_eq!(release_pressure(&input, &Options { agents: 1, minutes: 1 }).unwrap().pressure, 0);
+
+        // AA and a line of valves with flow
+        let valve_line = |n: u8| {
+            let names = (0..n).map(|i| format!("B{}", (b'A' + i) as char)).collect::<Vec<_>>();
+            let mut lines = vec!["Valve AA has flow rate=0; tunnels lead to valves BA".to_string()];
+            for (i, name) in names.iter().enumerate() {
+                let prev = if i == 0 { "AA" } else { &names[i - 1] };
+                let tunnels = names.get(i + 1).map(|next| format!("{}, {}", prev, next)).unwrap_or(prev.to_string());
+                lines.push(format!("Valve {} has flow rate={}; tunnels lead to valves {}", name, i + 1, tunnels));
+            }
+            lines
+        };
+        let e = parse(&valve_line(21)).unwrap_err();
+        assert_eq!(e.to_string(), "day 16: more than 20 valves with a flow rate are not supported");
+        let input = parse(&valve_line(17)).unwrap();
+        let e = release_pressure(&input, &Options { agents: 3, minutes: 26 }).unwrap_err();
+        assert_eq!(e, "3 agents only work with up to 16 valves with a flow rate but there are 17");
+        assert!(release_pressure(&input, &Options { agents: 2, minutes: 26 }).is_ok());
+    }
+}
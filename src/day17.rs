@@ -0,0 +1,573 @@
+use std::cmp::PartialEq;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+use crate::cycle::{find_cycle, Cycle};
+use crate::json_string;
+use crate::solver::Solver;
+use crate::error::Error;
+
+const DAY: usize = 17;
+
+#[derive(Debug, Copy, Clone)]
+pub enum Push {
+    Left,
+    Right,
+}
+
+impl Display for Push {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Push::Left => write!(f, "left"),
+            Push::Right => write!(f, "right"),
+        }
+    }
+}
+
+impl TryFrom<char> for Push {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '>' => Ok(Push::Right),
+            '<' => Ok(Push::Left),
+            _ => Err(c),
+        }
+    }
+}
+
+// the rocks of the puzzle in the order they fall
+pub const DEFAULT_SHAPES: &str = "\
+####
+
+.#.
+###
+.#.
+
+..#
+..#
+###
+
+#
+#
+#
+#
+
+##
+##";
+
+// a rock as (x, y) of its cells, y = 0 is the bottom row and x = 0 the left column
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RockShape {
+    pub body: Vec<(usize, usize)>,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl RockShape {
+    // ascii art like in the puzzle text, '#' is rock and '.' is air
+    pub fn parse(art: &[&str]) -> Result<RockShape, String> {
+        let mut body = vec![];
+        let height = art.len();
+        for (row, line) in art.iter().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                match c {
+                    '#' => body.push((x, height - 1 - row)),
+                    '.' => {}
+                    _ => return Err(format!("unexpected character '{}' in rock shape", c)),
+                }
+            }
+        }
+        if body.is_empty() {
+            return Err("rock shape without any rock".to_string());
+        }
+        // sorted by row and then left to right
+        body.sort_by_key(|(x, y)| (*y, *x));
+        let width = body.iter().map(|(x, _)| x + 1).max().unwrap();
+        let height = body.iter().map(|(_, y)| y + 1).max().unwrap();
+        Ok(RockShape { body, width, height })
+    }
+}
+
+// shapes separated by empty lines
+pub fn parse_shapes(art: &str) -> Result<Vec<RockShape>, String> {
+    let lines = art.lines().map(|l| l.trim()).collect::<Vec<_>>();
+    let shapes = lines
+        .split(|l| l.is_empty())
+        .filter(|shape| !shape.is_empty())
+        .map(RockShape::parse)
+        .collect::<Result<Vec<_>, _>>()?;
+    if shapes.is_empty() {
+        return Err("no rock shapes".to_string());
+    }
+    Ok(shapes)
+}
+
+pub const MAX_CHAMBER_WIDTH: usize = 64;
+
+#[derive(Debug, Clone)]
+pub struct ChamberConfig {
+    pub width: usize,
+    // a new rock appears spawn_x from the left wall and
+    // with spawn_y empty rows above the highest rock or the floor
+    pub spawn_x: usize,
+    pub spawn_y: usize,
+    pub shapes: Vec<RockShape>,
+}
+
+impl ChamberConfig {
+    pub fn new(width: usize, spawn_x: usize, spawn_y: usize, shapes: Vec<RockShape>) -> Result<ChamberConfig, String> {
+        if width == 0 || width > MAX_CHAMBER_WIDTH {
+            return Err(format!("chamber width must be between 1 and {}", MAX_CHAMBER_WIDTH));
+        }
+        if shapes.is_empty() {
+            return Err("no rock shapes".to_string());
+        }
+        if let Some(shape) = shapes.iter().find(|shape| spawn_x + shape.width > width) {
+            return Err(format!("a rock of width {} spawned at {} does not fit into the chamber", shape.width, spawn_x));
+        }
+        Ok(ChamberConfig { width, spawn_x, spawn_y, shapes })
+    }
+}
+
+impl Default for ChamberConfig {
+    // the chamber of the puzzle
+    fn default() -> Self {
+        ChamberConfig::new(7, 2, 3, parse_shapes(DEFAULT_SHAPES).unwrap()).unwrap()
+    }
+}
+
+#[derive(Debug)]
+#[derive(Eq, Hash, PartialEq)]
+struct Rock {
+    shape_idx: usize,
+    body: Vec<(usize, usize)>,
+}
+
+// rows below the top that are looked at to tell two towers apart
+// a guess that is deep enough for the puzzle, a cycle found with it is checked before it is used
+const SURFACE_DEPTH: usize = 64;
+
+// stopped rocks are only kept as bits in their rows
+#[derive(Debug)]
+struct Chamber {
+    // one bit per column
+    impact_area_bit: HashMap<usize, u64>,
+    // rows with rock in them
+    height: usize,
+    width: usize,
+}
+
+impl Chamber {
+    fn area_free(&self, area: &[(usize, usize)]) -> bool {
+        for pos in area.iter() {
+            let mask = self.impact_area_bit.get(&pos.1).unwrap_or(&0u64);
+            if mask & (1 << pos.0) != 0 {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn add_rock(&mut self, rock: &Rock) {
+        for pos in rock.body.iter() {
+            let line_bit = self.impact_area_bit.get(&pos.1).unwrap_or(&0u64);
+            let line_bit_new = line_bit | 1 << pos.0;
+            self.impact_area_bit.insert(pos.1, line_bit_new);
+        }
+        self.height = self.height.max(rock.get_top() + 1);
+    }
+
+    #[allow(dead_code)]
+    fn print_chamber(&self, falling_rock: Option<&Rock>) {
+        println!("{}", self.render(falling_rock, usize::MAX));
+    }
+
+    // like in the puzzle text with the falling rock as '@'
+    // only the highest window rows are drawn, the floor when it is one of them
+    fn render(&self, falling_rock: Option<&Rock>, window: usize) -> String {
+        let rows = falling_rock.map(|rock| rock.get_top() + 1).unwrap_or(0).max(self.height);
+        let bottom = rows.saturating_sub(window);
+        let mut picture = String::new();
+        for y in (bottom..rows).rev() {
+            picture.push('|');
+            for x in 0..self.width {
+                let mask = self.impact_area_bit.get(&y).unwrap_or(&0u64);
+                if falling_rock.is_some_and(|rock| rock.body.contains(&(x, y))) {
+                    picture.push('@');
+                } else if mask & (1 << x) != 0 {
+                    picture.push('#');
+                } else {
+                    picture.push('.');
+                }
+            }
+            picture.push_str("|\n");
+        }
+        if bottom == 0 {
+            picture.push_str(&format!("+{}+\n", "-".repeat(self.width)));
+        }
+        picture
+    }
+
+    // the free cells a falling rock can still get to, row by row from the top down
+    // rocks only move down, left and right so a row only depends on the one above
+    // anything deeper than SURFACE_DEPTH is taken as out of reach
+    fn surface(&self) -> Vec<u64> {
+        let all = u64::MAX >> (64 - self.width);
+        let mut surface = vec![];
+        let mut reach = all;
+        for y in (0..self.height).rev().take(SURFACE_DEPTH) {
+            let free = !self.impact_area_bit.get(&y).unwrap_or(&0u64) & all;
+            let mut row = reach & free;
+            loop {
+                let spread = (row | row << 1 | row >> 1) & free;
+                if spread == row {
+                    break;
+                }
+                row = spread;
+            }
+            if row == 0 {
+                break;
+            }
+            surface.push(row);
+            reach = row;
+        }
+        surface
+    }
+}
+
+impl Rock {
+    fn new(shape_idx: usize, shape: &RockShape, left: usize, top: usize) -> Self {
+        Rock {
+            shape_idx,
+            body: shape.body.iter().map(|(x, y)| (left + x, top + y)).collect(),
+        }
+    }
+    fn move_down(&mut self, chamber: &Chamber) -> bool {
+        // println!("move_down {:?} {}", self, chamber.get_lowest_rock_y());
+        if self.get_bottom() == 0 {
+            return false;
+        }
+        let new_area = self.create_move_area();
+        if chamber.area_free(&new_area) {
+            self.apply_new_area(&new_area);
+            return true;
+        }
+        false
+    }
+
+    fn get_top(&self) -> usize {
+        self.body.iter().map(|pos| pos.1).max().unwrap()
+    }
+
+    fn get_bottom(&self) -> usize {
+        self.body.iter().map(|pos| pos.1).min().unwrap()
+    }
+
+    fn create_push_area(&self, push: &Push) -> Vec<(usize, usize)> {
+        let mut new_pos = vec![];
+        match push {
+            Push::Left => {
+                self.body.iter().for_each(|element| new_pos.push((element.0 - 1, element.1)));
+            }
+            Push::Right => {
+                self.body.iter().for_each(|element| new_pos.push((element.0 + 1, element.1)));
+            }
+        }
+        new_pos
+    }
+
+    fn create_move_area(&self) -> Vec<(usize, usize)> {
+        let mut new_pos = vec![];
+        self.body.iter().for_each(|element| new_pos.push((element.0, element.1 - 1)));
+        new_pos
+    }
+
+    fn apply_new_area(&mut self, area: &[(usize, usize)]) {
+        self.body.clear();
+        area.iter().for_each(|element| self.body.push(*element))
+    }
+
+    fn get_left(&self) -> usize {
+        self.body.iter().map(|pos| pos.0).min().unwrap()
+    }
+
+    fn get_right(&self) -> usize {
+        self.body.iter().map(|pos| pos.0).max().unwrap()
+    }
+
+    fn can_push(&self, chamber: &Chamber, push: &Push) -> bool {
+        match push {
+            Push::Left => self.get_left() > 0,
+            Push::Right => self.get_right() < chamber.width - 1
+        }
+    }
+
+    // false if a wall or another rock is in the way
+    fn push_it(&mut self, chamber: &Chamber, push: &Push) -> bool {
+        // println!("try push_it {:?} {:?}", self, push);
+
+        if self.can_push(chamber, push) {
+            // println!("push_it {:?} {:?}", self, push);
+            let new_area = self.create_push_area(push);
+            if chamber.area_free(&new_area) {
+                self.apply_new_area(&new_area);
+                return true;
+            }
+        }
+        false
+    }
+}
+
+// what a rock did, with the push whether it moved
+#[derive(Debug, Copy, Clone)]
+enum Motion {
+    Appear,
+    Push(Push, bool),
+    Fall,
+    Rest,
+}
+
+// everything that decides how the next rocks fall
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct TowerState {
+    shape_idx: usize,
+    push_idx: usize,
+    surface: Vec<u64>,
+}
+
+pub fn parse(lines: &[String]) -> Result<Vec<Push>, Error> {
+    let line = lines.first().ok_or_else(|| Error::input(DAY, "empty input"))?;
+    let mut push_list = vec![];
+    for (x, c) in line.trim_end().chars().enumerate() {
+        let d = Push::try_from(c).map_err(|c| Error::unexpected_char(DAY, 0, x, c))?;
+        push_list.push(d);
+    }
+    if push_list.is_empty() {
+        return Err(Error::input(DAY, "no jets in the input"));
+    }
+    Ok(push_list)
+}
+
+// drops one rock after the other
+struct Simulation<'a> {
+    push_list: &'a [Push],
+    config: &'a ChamberConfig,
+    chamber: Chamber,
+    rock_idx: usize,
+    push_idx: usize,
+}
+
+impl<'a> Simulation<'a> {
+    fn new(push_list: &'a [Push], config: &'a ChamberConfig) -> Self {
+        Simulation {
+            push_list,
+            config,
+            chamber: Chamber {
+                impact_area_bit: HashMap::new(),
+                height: 0,
+                width: config.width,
+            },
+            rock_idx: 0,
+            push_idx: 0,
+        }
+    }
+
+    fn drop_rock(&mut self) {
+        self.drop_rock_watched(|_, _, _| {});
+    }
+
+    // watch sees the chamber and the falling rock after every motion,
+    // once the rock came to rest it is part of the chamber
+    fn drop_rock_watched<F>(&mut self, mut watch: F)
+    where
+        F: FnMut(&Chamber, Option<&Rock>, Motion),
+    {
+        let shape_idx = self.rock_idx % self.config.shapes.len();
+        let shape = &self.config.shapes[shape_idx];
+        let mut rock = Rock::new(shape_idx, shape, self.config.spawn_x, self.chamber.height + self.config.spawn_y);
+        watch(&self.chamber, Some(&rock), Motion::Appear);
+        loop {
+            let push = self.push_list[self.push_idx];
+            let moved = rock.push_it(&self.chamber, &push);
+            watch(&self.chamber, Some(&rock), Motion::Push(push, moved));
+            self.push_idx = (self.push_idx + 1) % self.push_list.len();
+            if !rock.move_down(&self.chamber) {
+                break;
+            }
+            watch(&self.chamber, Some(&rock), Motion::Fall);
+        }
+        self.chamber.add_rock(&rock);
+        watch(&self.chamber, None, Motion::Rest);
+        self.rock_idx += 1;
+    }
+
+    fn state(&self) -> TowerState {
+        TowerState {
+            shape_idx: self.rock_idx % self.config.shapes.len(),
+            push_idx: self.push_idx,
+            surface: self.chamber.surface(),
+        }
+    }
+}
+
+// the height of the tower after rocks fell
+pub fn tower_height(push_list: &[Push], config: &ChamberConfig, rocks: usize) -> usize {
+    let mut simulation = Simulation::new(push_list, config);
+    for _ in 0..rocks {
+        simulation.drop_rock();
+    }
+    simulation.chamber.height
+}
+
+// drops rocks until the top of the tower looks like it did before
+// steps of the cycle are rocks and the value is the height of the tower
+// the surface only covers SURFACE_DEPTH rows, so the cycle is dropped once more
+// and an error if the tower does not grow the same way again
+pub fn find_tower_cycle(push_list: &[Push], config: &ChamberConfig) -> Result<Cycle, String> {
+    let mut simulation = Simulation::new(push_list, config);
+    let steps = std::iter::from_fn(|| {
+        let step = (simulation.state(), simulation.chamber.height as i64);
+        simulation.drop_rock();
+        Some(step)
+    });
+    // there are only so many states so the rocks never run out
+    let cycle = find_cycle(steps).unwrap();
+    // the steps were one rock ahead of the cycle
+    for rocks in cycle.start + cycle.length + 1..cycle.start + 2 * cycle.length + 1 {
+        if simulation.chamber.height as i64 != cycle.value_at(rocks as u64) {
+            return Err(format!("after {} rocks the tower does not follow the {}", rocks, cycle));
+        }
+        simulation.drop_rock();
+    }
+    Ok(cycle)
+}
+
+// same as tower_height but for a number of rocks that can only be extrapolated
+pub fn tower_height_with_cycle(push_list: &[Push], config: &ChamberConfig, rocks: u64) -> Result<u64, String> {
+    Ok(find_tower_cycle(push_list, config)?.value_at(rocks) as u64)
+}
+
+// one picture of the animation, rock counts from 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub rock: usize,
+    pub caption: String,
+    pub picture: String,
+}
+
+// a frame for every push and fall of the first rocks,
+// window is the number of rows below the top that are drawn
+pub fn animate(push_list: &[Push], config: &ChamberConfig, rocks: usize, window: usize) -> Vec<Frame> {
+    let mut simulation = Simulation::new(push_list, config);
+    let mut frames = vec![];
+    for rock in 1..rocks + 1 {
+        simulation.drop_rock_watched(|chamber, falling_rock, motion| {
+            let caption = match motion {
+                Motion::Appear => format!("Rock {} begins falling:", rock),
+                Motion::Push(push, true) => format!("Jet of gas pushes rock {}:", push),
+                Motion::Push(push, false) => format!("Jet of gas pushes rock {}, but nothing happens:", push),
+                Motion::Fall => "Rock falls 1 unit:".to_string(),
+                Motion::Rest => "Rock falls 1 unit, causing it to come to rest:".to_string(),
+            };
+            let picture = chamber.render(falling_rock, window);
+            frames.push(Frame { rock, caption, picture });
+        });
+    }
+    frames
+}
+
+// the frames one after the other like in the puzzle text
+pub fn frames_to_text(frames: &[Frame]) -> String {
+    frames.iter().map(|frame| format!("{}\n{}", frame.caption, frame.picture)).collect::<Vec<_>>().join("\n")
+}
+
+// an asciicast v2 recording that asciinema can play, a new frame every delay
+pub fn frames_to_asciicast(frames: &[Frame], delay: Duration) -> String {
+    let lines = frames.iter().flat_map(|frame| std::iter::once(frame.caption.as_str()).chain(frame.picture.lines()));
+    let width = lines.map(|line| line.chars().count()).max().unwrap_or(0);
+    let height = frames.iter().map(|frame| frame.picture.lines().count() + 1).max().unwrap_or(0);
+    let mut cast = format!("{{\"version\": 2, \"width\": {}, \"height\": {}}}\n", width, height);
+    for (i, frame) in frames.iter().enumerate() {
+        // clear the screen and draw the frame from the upper left
+        let screen = format!("\x1b[H\x1b[2J{}\r\n{}", frame.caption, frame.picture.replace('\n', "\r\n"));
+        cast += &format!("[{:.3}, \"o\", {}]\n", (delay * i as u32).as_secs_f64(), json_string(&screen));
+    }
+    cast
+}
+
+pub fn part1(push_list: &[Push]) -> usize {
+    tower_height(push_list, &ChamberConfig::default(), 2022)
+}
+
+pub fn part2(push_list: &[Push]) -> Result<u64, String> {
+    // 1535483870924
+    tower_height_with_cycle(push_list, &ChamberConfig::default(), 1000000000000)
+}
+
+pub struct Day17;
+
+impl Solver for Day17 {
+    type Input = Vec<Push>;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, Error> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, Error> {
+        Ok(part1(input).to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, Error> {
+        part2(input).map(|height| height.to_string()).map_err(|e| Error::input(DAY, &e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use super::{animate, find_tower_cycle, frames_to_asciicast, frames_to_text, parse, parse_shapes, part1, part2, tower_height, tower_height_with_cycle, ChamberConfig};
+
+    #[test]
+    fn it_works() {
+        let lines = [">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>"].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        let input = parse(&lines).unwrap();
+
+        let result = part1(&input);
+        assert_eq!(result, 3068);
+        let result = part2(&input);
+        assert_eq!(result, Ok(1514285714288));
+
+        let config = ChamberConfig::default();
+        let cycle = find_tower_cycle(&input, &config).unwrap();
+        assert_eq!((cycle.length, cycle.delta), (35, 53));
+        for rocks in [0, 1, 2022, 5000, 7777] {
+            assert_eq!(tower_height_with_cycle(&input, &config, rocks as u64), Ok(tower_height(&input, &config, rocks) as u64));
+        }
+
+        // a wider chamber with other rocks
+        let shapes = parse_shapes("###\n#.#\n\n##\n.#").unwrap();
+        assert_eq!(shapes[0].body, vec![(0, 0), (2, 0), (0, 1), (1, 1), (2, 1)]);
+        let config = ChamberConfig::new(12, 3, 2, shapes).unwrap();
+        assert_eq!(tower_height(&input, &config, 1), 2);
+        // rocks filling the whole width just stack up
+        let config = ChamberConfig::new(10, 0, 3, parse_shapes("##########").unwrap()).unwrap();
+        assert_eq!(tower_height(&input, &config, 100), 100);
+        assert!(ChamberConfig::new(65, 2, 3, parse_shapes("#").unwrap()).is_err());
+        assert!(parse_shapes("#x").is_err());
+
+        let frames = animate(&input, &ChamberConfig::default(), 2, 5);
+        assert_eq!(frames[0].caption, "Rock 1 begins falling:");
+        assert_eq!(frames[0].picture, "|..@@@@.|\n|.......|\n|.......|\n|.......|\n+-------+\n");
+        assert_eq!(frames[3].caption, "Jet of gas pushes rock right, but nothing happens:");
+        assert_eq!(frames[8].caption, "Rock falls 1 unit, causing it to come to rest:");
+        assert_eq!(frames[8].picture, "|..####.|\n+-------+\n");
+        // the second rock appears higher than the window
+        assert_eq!(frames[9].picture, "|...@...|\n|..@@@..|\n|...@...|\n|.......|\n|.......|\n");
+        assert!(frames_to_text(&frames[..2]).starts_with("Rock 1 begins falling:\n|..@@@@.|\n"));
+        let cast = frames_to_asciicast(&frames, Duration::from_millis(250));
+        assert_eq!(cast.lines().next().unwrap(), "{\"version\": 2, \"width\": 50, \"height\": 7}");
+        assert!(cast.lines().nth(2).unwrap().starts_with("[0.250, \"o\", \"\\u001b[H\\u001b[2JJet of gas pushes rock right:\\r\\n|...@@@@|\\r\\n"));
+    }
+}
@@ -0,0 +1,429 @@
+use itertools::Itertools;
+use std::cmp::max;
+use std::collections::HashSet;
+use std::ops::Add;
+use crate::solver::Solver;
+use crate::error::{parse_number, Error};
+
+const DAY: usize = 18;
+
+// thx to https://gitlab.com/harudagondi/alg-grid/-/blob/82a4951f244f8fb86f550970d1807971f8477e4c/src/three_dim.rs
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Hash)]
+pub struct Point3D {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl Add for Point3D {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+fn get_neighbors(point: Point3D) -> [Point3D; 6] {
+    [
+        // centers
+        point + Point3D {
+            x: 0,
+            y: 1,
+            z: 0,
+        },
+        point + Point3D {
+            x: 0,
+            y: -1,
+            z: 0,
+        },
+        point + Point3D {
+            x: 1,
+            y: 0,
+            z: 0,
+        },
+        point + Point3D {
+            x: -1,
+            y: 0,
+            z: 0,
+        },
+        point + Point3D {
+            x: 0,
+            y: 0,
+            z: 1,
+        },
+        point + Point3D {
+            x: 0,
+            y: 0,
+            z: -1,
+        },
+    ]
+}
+
+pub fn parse(lines: &[String]) -> Result<Vec<Point3D>, Error> {
+    let mut cubes = vec![];
+    for (line_idx, coords) in lines.iter().enumerate() {
+        let cube_coords = coords.split(",").map(|x| parse_number(DAY, line_idx, x)).collect::<Result<Vec<i32>, Error>>()?;
+        if cube_coords.len() != 3 {
+            return Err(Error::parse(DAY, line_idx, "expected x,y,z"));
+        }
+        // the air search starts at 0,0,0
+        if cube_coords.iter().any(|c| *c < 0) {
+            return Err(Error::parse(DAY, line_idx, "negative coordinates are not supported"));
+        }
+        let x = cube_coords[0];
+        let y = cube_coords[1];
+        let z = cube_coords[2];
+        let cube = Point3D {
+            x,
+            y,
+            z,
+        };
+        cubes.push(cube);
+    }
+    Ok(cubes)
+}
+
+fn is_neighbor(p1: Point3D, p2: Point3D) -> bool {
+    get_neighbors(p1).contains(&p2)
+}
+
+fn is_inside(p: Point3D, max_x: i32, max_y: i32, max_z: i32) -> bool {
+    (0..max_x + 1).contains(&p.x) &&  (0..max_y + 1).contains(&p.y) && (0..max_z + 1).contains(&p.z)
+}
+
+pub fn part1(cube_list: &[Point3D]) -> u32 {
+    // 3522
+    let cubes: HashSet<Point3D> = cube_list.iter().copied().collect();
+    let open_side = get_open_sides(&cubes);
+    open_side as u32
+}
+
+fn get_enclosed_cubes(cubes: &HashSet<Point3D>, max_x: i32, max_y: i32, max_z: i32) -> HashSet<Point3D> {
+    let mut enclosed_cubes = HashSet::new();
+    for z in 0..max_z + 1 {
+        for y in 0..max_y + 1 {
+            for x in 0..max_x + 1 {
+                let c = Point3D {
+                    x,
+                    y,
+                    z,
+                };
+                // must be air
+                if !cubes.contains(&c) && !enclosed_cubes.contains(&c)
+                    && is_enclosed_air(cubes, &c, max_x, max_y, max_z) {
+                        enclosed_cubes.insert(c);
+                    }
+            }
+        }
+    }
+    enclosed_cubes
+}
+
+fn is_enclosed_air(cubes: &HashSet<Point3D>, cube: &Point3D, max_x: i32, max_y: i32, max_z: i32) -> bool {
+    let this_x = cube.x;
+
+    let mut enclosed_left = false;
+    let mut enclosed_right = false;
+
+    for x in (0..this_x).rev() {
+        let c = Point3D {
+            x,
+            y: cube.y,
+            z: cube.z,
+        };
+        if cubes.contains(&c) {
+            enclosed_left = true;
+            break;
+        }
+    }
+    for x in this_x..max_x + 1 {
+        let c = Point3D {
+            x,
+            y: cube.y,
+            z: cube.z,
+        };
+        if cubes.contains(&c) {
+            enclosed_right = true;
+            break;
+        }
+    }
+    if !(enclosed_left && enclosed_right) {
+        return false;
+    }
+
+    let this_y = cube.y;
+
+    let mut enclosed_left = false;
+    let mut enclosed_right = false;
+
+    for y in (0..this_y).rev() {
+        let c = Point3D {
+            x: cube.x,
+            y,
+            z: cube.z,
+        };
+        if cubes.contains(&c) {
+            enclosed_left = true;
+            break;
+        }
+    }
+    for y in this_y..max_y + 1 {
+        let c = Point3D {
+            x: cube.x,
+            y,
+            z: cube.z,
+        };
+        if cubes.contains(&c) {
+            enclosed_right = true;
+            break;
+        }
+    }
+    if !(enclosed_left && enclosed_right) {
+        return false;
+    }
+
+    let this_z = cube.z;
+
+    let mut enclosed_left = false;
+    let mut enclosed_right = false;
+
+    for z in (0..this_z).rev() {
+        let c = Point3D {
+            x: cube.x,
+            y: cube.y,
+            z,
+        };
+        if cubes.contains(&c) {
+            enclosed_left = true;
+            break;
+        }
+    }
+    for z in this_z..max_z + 1 {
+        let c = Point3D {
+            x: cube.x,
+            y: cube.y,
+            z,
+        };
+        if cubes.contains(&c) {
+            enclosed_right = true;
+            break;
+        }
+    }
+    if !(enclosed_left && enclosed_right) {
+        return false;
+    }
+
+    true
+}
+
+#[allow(dead_code)]
+fn print_cube(cubes: &HashSet<Point3D>, enclosed_cubes: &HashSet<Point3D>, max_x: i32, max_y: i32, max_z: i32) {
+    for z in 0..max_z + 1 {
+        println!("{}", z);
+        for y in 0..max_y + 1 {
+            for x in 0..max_x + 1 {
+                let c = Point3D {
+                    x,
+                    y,
+                    z,
+                };
+                if enclosed_cubes.contains(&c) {
+                    print!("@")
+                } else if cubes.contains(&c) {
+                    print!("#")
+                } else {
+                    print!(".")
+                }
+            }
+            println!();
+        }
+        println!();
+    }
+}
+fn get_open_sides(cubes: &HashSet<Point3D>) -> usize {
+    let mut open_side = cubes.len() * 6;
+
+    for pair in cubes.iter().combinations(2) {
+        let p1 = *pair.first().unwrap();
+        let p2 = *pair.last().unwrap();
+        if is_neighbor(*p1, *p2) {
+            open_side -= 2;
+        }
+    }
+
+    open_side
+}
+
+fn filter_wrong_enclosed(cubes: &HashSet<Point3D>, enclosed_cubes: &HashSet<Point3D>) -> HashSet<Point3D> {
+    let mut false_positives = HashSet::new();
+    for &e in enclosed_cubes {
+        if !get_neighbors(e)
+            .iter()
+            .all(|&x| cubes.contains(&x) || enclosed_cubes.contains(&x)) {
+            false_positives.insert(e);
+        }
+    }
+    false_positives
+}
+
+fn fill_air(cubes: &HashSet<Point3D>, current: Point3D, visited: &mut HashSet<Point3D>, max_x: i32, max_y: i32, max_z: i32) {
+    for c in get_neighbors(current) {
+        if is_inside(c, max_x, max_y, max_z)
+            && !cubes.contains(&c) && !visited.contains(&c) {
+                visited.insert(c);
+                fill_air(cubes, c, visited, max_x, max_y, max_z);
+            }
+    }
+}
+
+// lucky this worked with just 2 iterations
+// wrongly assume enclosed just extends x y z
+// then check for those if all their neighbours are
+// either cubes or also enclosed - that way we catch
+// the ones that still have air around like
+// # . #
+// @ . #
+// # # #
+// repeat this until we find no more wrong ones
+pub fn part2(cube_list: &[Point3D]) -> u32 {
+    // 2074
+    let mut cubes = HashSet::new();
+    let mut max_x = 0;
+    let mut max_y = 0;
+    let mut max_z = 0;
+
+    // let mut open_sides = part1(cube_list);
+
+    for cube in cube_list.iter() {
+        max_x = max(max_x, cube.x);
+        max_y = max(max_y, cube.y);
+        max_z = max(max_z, cube.z);
+
+        cubes.insert(*cube);
+    }
+
+    let mut enclosed_cubes = get_enclosed_cubes(&cubes, max_x, max_y, max_z);
+    // println!("enclosed_cubes {}", enclosed_cubes.len());
+
+    // print_cube(&cubes, &enclosed_cubes, max_x, max_y, max_z);
+
+    let mut false_positives = filter_wrong_enclosed(&cubes, &enclosed_cubes);
+    while !false_positives.is_empty() {
+        // println!("false_positives {}", false_positives.len());
+
+        enclosed_cubes.retain(|&c| !false_positives.contains(&c));
+        false_positives = filter_wrong_enclosed(&cubes, &enclosed_cubes);
+    }
+
+    // print_cube(&cubes, &final_enclosed, max_x, max_y, max_z);
+
+    cubes.extend(&enclosed_cubes);
+
+    let open_side = get_open_sides(&cubes);
+    open_side as u32
+}
+
+// brute force but for sure works
+// collect all outside - all points that are not in cubes recursive
+// then remove those and cubes from all to get the remaining which
+// must be inside. Then same as before
+#[allow(dead_code)]
+fn part22(cube_list: &[Point3D]) -> u32 {
+    // 2074
+    let mut cubes = HashSet::new();
+    let mut max_x = 0;
+    let mut max_y = 0;
+    let mut max_z = 0;
+
+    // let mut open_sides = part1(cube_list);
+
+    for cube in cube_list.iter() {
+        max_x = max(max_x, cube.x);
+        max_y = max(max_y, cube.y);
+        max_z = max(max_z, cube.z);
+
+        cubes.insert(*cube);
+    }
+
+    let mut air_cubes = HashSet::new();
+    let start = Point3D {
+        x: 0,
+        y: 0,
+        z: 0,
+    };
+    fill_air(&cubes, start, &mut air_cubes, max_x, max_y, max_z);
+
+    // println!("air_cubes {}", air_cubes.len());
+
+    let mut enclosed_cubes = HashSet::new();
+    for z in 0..max_z + 1 {
+        for y in 0..max_y + 1 {
+            for x in 0..max_x + 1 {
+                let c = Point3D {
+                    x,
+                    y,
+                    z,
+                };
+                if !cubes.contains(&c) && !air_cubes.contains(&c) {
+                    enclosed_cubes.insert(c);
+                }
+            }
+        }
+    }
+    // println!("enclosed_cubes {}", enclosed_cubes.len());
+
+    cubes.extend(&enclosed_cubes);
+    let open_side = get_open_sides(&cubes);
+    open_side as u32
+}
+
+pub struct Day18;
+
+impl Solver for Day18 {
+    type Input = Vec<Point3D>;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, Error> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, Error> {
+        Ok(part1(input).to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, Error> {
+        Ok(part2(input).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+
+    #[test]
+    fn it_works() {
+        let lines = vec!["2,2,2",
+                         "1,2,2",
+                         "3,2,2",
+                         "2,1,2",
+                         "2,3,2",
+                         "2,2,1",
+                         "2,2,3",
+                         "2,2,4",
+                         "2,2,6",
+                         "1,2,5",
+                         "3,2,5",
+                         "2,1,5",
+                         "2,3,5"].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        let input = parse(&lines).unwrap();
+
+        let result = part1(&input);
+        assert_eq!(result, 64);
+        let result = part2(&input);
+        assert_eq!(result, 58);
+    }
+}
@@ -0,0 +1,564 @@
+use regex::Regex;
+use std::collections::{HashSet, VecDeque};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use crate::solver::{Options, Solver};
+use crate::error::{parse_number, Error};
+
+const DAY: usize = 19;
+
+// what happens in one minute
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BuildPlan {
+    Nothing,
+    BuildOreRobot,
+    BuildClayRobot,
+    BuildObsidianRobot,
+    BuildGeodeRobot,
+}
+
+#[derive(Debug, Clone)]
+pub struct Blueprint {
+    pub id: u16,
+    pub ore_robot_cost: u16,
+    pub clay_robot_cost: u16,
+    pub obs_robot_cost_ore: u16,
+    pub obs_robot_cost_clay: u16,
+    pub geode_robot_cost_ore: u16,
+    pub geode_robot_cost_obs: u16,
+}
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+struct Resources {
+    ore_robot_num: u16,
+    ore_num: u16,
+    clay_robot_num: u16,
+    clay_num: u16,
+    obs_robot_num: u16,
+    obs_num: u16,
+    geode_robot_num: u16,
+    geode_num: u16,
+    time: u16,
+}
+
+fn get_possible_plans(blueprint: &Blueprint, resources: &Resources) -> Vec<BuildPlan> {
+    let mut plans = vec![];
+    if resources.obs_num >= blueprint.geode_robot_cost_obs
+        && resources.ore_num >= blueprint.geode_robot_cost_ore
+    {
+        plans.push(BuildPlan::BuildGeodeRobot);
+    }
+    if resources.clay_num >= blueprint.obs_robot_cost_clay
+        && resources.ore_num >= blueprint.obs_robot_cost_ore
+    {
+        plans.push(BuildPlan::BuildObsidianRobot);
+    }
+    if resources.ore_num >= blueprint.clay_robot_cost {
+        plans.push(BuildPlan::BuildClayRobot);
+    }
+    if resources.ore_num >= blueprint.ore_robot_cost {
+        plans.push(BuildPlan::BuildOreRobot);
+    }
+
+    plans
+}
+
+fn update_resources(resources: &Resources, minute: u16) -> Resources {
+    let mut r = resources.clone();
+    r.ore_num += r.ore_robot_num;
+    r.clay_num += r.clay_robot_num;
+    r.obs_num += r.obs_robot_num;
+    r.geode_num += r.geode_robot_num;
+    r.time = minute;
+    r
+}
+
+fn build_robots(
+    blueprint: &Blueprint,
+    resources: &Resources,
+    plan: &BuildPlan,
+    minute: u16,
+) -> Resources {
+    let mut r = resources.clone();
+    r.ore_num += r.ore_robot_num;
+    r.clay_num += r.clay_robot_num;
+    r.obs_num += r.obs_robot_num;
+    r.geode_num += r.geode_robot_num;
+    r.time = minute;
+
+    match plan {
+        BuildPlan::Nothing => {}
+        BuildPlan::BuildOreRobot => {
+            r.ore_robot_num += 1;
+            r.ore_num -= blueprint.ore_robot_cost
+        }
+        BuildPlan::BuildClayRobot => {
+            r.clay_robot_num += 1;
+            r.ore_num -= blueprint.clay_robot_cost
+        }
+        BuildPlan::BuildObsidianRobot => {
+            r.obs_robot_num += 1;
+            r.ore_num -= blueprint.obs_robot_cost_ore;
+            r.clay_num -= blueprint.obs_robot_cost_clay
+        }
+        BuildPlan::BuildGeodeRobot => {
+            r.geode_robot_num += 1;
+            r.ore_num -= blueprint.geode_robot_cost_ore;
+            r.obs_num -= blueprint.geode_robot_cost_obs
+        }
+    }
+    r
+}
+
+// the most geodes a search found and how many states it looked at
+// only the depth first search knows the plan, one entry per minute,
+// the breadth first search merges states and can not tell how it got there
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeodeSearch {
+    pub geodes: u16,
+    pub states: usize,
+    pub plan: Option<Vec<BuildPlan>>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SearchMethod {
+    // every state minute by minute
+    Bfs,
+    // robot by robot with an upper bound
+    Dfs,
+}
+
+fn try_blueprint2(blueprint: &Blueprint, resources: &Resources) -> GeodeSearch {
+    let mut queue: VecDeque<Resources> = VecDeque::new();
+    queue.push_back(resources.clone());
+
+    let mut visited: HashSet<Resources> = HashSet::new();
+    let mut max = 0;
+    while !queue.is_empty() {
+        let mut r = queue.pop_front().unwrap();
+
+        if visited.contains(&r) {
+            continue;
+        }
+        visited.insert(r.clone());
+
+        let minute = r.time;
+        if minute == 0 {
+            if r.geode_num > max {
+                max = r.geode_num
+            }
+            continue;
+        }
+        if minute == 1
+            && r.geode_robot_num == 0 {
+                continue;
+            }
+        if minute == 2
+            && r.obs_robot_num == 0 {
+                continue;
+            }
+        if minute == 3
+            && r.clay_robot_num == 0 {
+                continue;
+            }
+
+        // from https://github.com/ritesh-singh/aoc-2022-kotlin/blob/main/src/day19/Day19.kt
+        let max_ores_required = *[blueprint.ore_robot_cost,
+            blueprint.clay_robot_cost,
+            blueprint.obs_robot_cost_ore,
+            blueprint.geode_robot_cost_ore]
+        .iter()
+        .max()
+        .unwrap();
+
+        // find max ores required for a robot
+        if r.ore_robot_num >= max_ores_required {
+            // max ores which can be utilized for building any type of robot per minute
+            r.ore_robot_num = max_ores_required;
+        }
+        if r.clay_robot_num >= blueprint.obs_robot_cost_clay {
+            // max clays which can be utilized for building any type of robot per minute
+            r.clay_robot_num = blueprint.obs_robot_cost_clay;
+        }
+        if r.obs_robot_num >= blueprint.geode_robot_cost_obs {
+            // max obsidian which can be utilized for building any type of robot per minute
+            r.obs_robot_num = blueprint.geode_robot_cost_obs;
+        }
+        // Reduce state by removing resources not required per minute
+        if r.ore_num >= r.time * max_ores_required - r.ore_robot_num * (r.time - 1) {
+            r.ore_num = r.time * max_ores_required - r.ore_robot_num * (r.time - 1)
+        }
+        if r.clay_num >= r.time * blueprint.obs_robot_cost_clay - r.clay_robot_num * (r.time - 1) {
+            r.clay_num = r.time * blueprint.obs_robot_cost_clay - r.clay_robot_num * (r.time - 1)
+        }
+        if r.obs_num >= r.time * blueprint.geode_robot_cost_obs - r.obs_robot_num * (r.time - 1) {
+            r.obs_num = r.time * blueprint.geode_robot_cost_obs - r.obs_robot_num * (r.time - 1)
+        }
+
+        // only collect resources
+        let r_new = update_resources(&r, minute - 1);
+        queue.push_back(r_new);
+
+        let plans = get_possible_plans(blueprint, &r);
+        for plan in plans.iter() {
+            // build and collect
+            let r_new = build_robots(blueprint, &r, plan, minute - 1);
+            queue.push_back(r_new);
+        }
+    }
+    GeodeSearch { geodes: max, states: visited.len(), plan: None }
+}
+
+// ore, clay and obsidian a robot costs
+fn robot_cost(blueprint: &Blueprint, plan: &BuildPlan) -> (u16, u16, u16) {
+    match plan {
+        BuildPlan::Nothing => (0, 0, 0),
+        BuildPlan::BuildOreRobot => (blueprint.ore_robot_cost, 0, 0),
+        BuildPlan::BuildClayRobot => (blueprint.clay_robot_cost, 0, 0),
+        BuildPlan::BuildObsidianRobot => (blueprint.obs_robot_cost_ore, blueprint.obs_robot_cost_clay, 0),
+        BuildPlan::BuildGeodeRobot => (blueprint.geode_robot_cost_ore, 0, blueprint.geode_robot_cost_obs),
+    }
+}
+
+// minutes of collecting until there is enough, None if a resource is not collected at all
+fn minutes_to_wait(cost: u16, num: u16, robot_num: u16) -> Option<u16> {
+    if num >= cost {
+        Some(0)
+    } else if robot_num == 0 {
+        None
+    } else {
+        Some((cost - num).div_ceil(robot_num))
+    }
+}
+
+// path holds the plan of the minutes that are over
+fn try_blueprint_dfs(blueprint: &Blueprint, r: &Resources, limits: &Resources, path: &mut Vec<BuildPlan>, search: &mut GeodeSearch) {
+    search.states += 1;
+    // the geode robots keep on working if nothing gets built anymore
+    let geodes = r.geode_num + r.geode_robot_num * r.time;
+    if geodes > search.geodes {
+        search.geodes = geodes;
+        let mut plan = path.clone();
+        plan.resize(path.len() + r.time as usize, BuildPlan::Nothing);
+        search.plan = Some(plan);
+    }
+    // even with a new geode robot every minute it would not be better
+    if geodes + r.time * r.time.saturating_sub(1) / 2 <= search.geodes {
+        return;
+    }
+
+    let plans = [BuildPlan::BuildGeodeRobot, BuildPlan::BuildObsidianRobot, BuildPlan::BuildClayRobot, BuildPlan::BuildOreRobot];
+    for plan in plans.iter() {
+        // more robots than can be spent each minute are useless
+        let enough = match plan {
+            BuildPlan::BuildOreRobot => r.ore_robot_num >= limits.ore_robot_num,
+            BuildPlan::BuildClayRobot => r.clay_robot_num >= limits.clay_robot_num,
+            BuildPlan::BuildObsidianRobot => r.obs_robot_num >= limits.obs_robot_num,
+            _ => false,
+        };
+        if enough {
+            continue;
+        }
+        let (ore, clay, obs) = robot_cost(blueprint, plan);
+        let wait = [
+            minutes_to_wait(ore, r.ore_num, r.ore_robot_num),
+            minutes_to_wait(clay, r.clay_num, r.clay_robot_num),
+            minutes_to_wait(obs, r.obs_num, r.obs_robot_num),
+        ];
+        let Some(wait) = wait.iter().copied().collect::<Option<Vec<_>>>().and_then(|w| w.into_iter().max()) else {
+            continue;
+        };
+        // a robot built in the last minute does not collect anything anymore
+        if wait + 1 >= r.time {
+            continue;
+        }
+        // skip the minutes until the robot can be built
+        let mut r_new = r.clone();
+        for _ in 0..wait {
+            r_new = update_resources(&r_new, r_new.time - 1);
+        }
+        let r_new = build_robots(blueprint, &r_new, plan, r_new.time - 1);
+        let len = path.len();
+        path.resize(len + wait as usize, BuildPlan::Nothing);
+        path.push(*plan);
+        try_blueprint_dfs(blueprint, &r_new, limits, path, search);
+        path.truncate(len);
+    }
+}
+
+pub fn search_blueprint(blueprint: &Blueprint, time: u16, method: SearchMethod) -> GeodeSearch {
+    match method {
+        SearchMethod::Bfs => try_blueprint2(blueprint, &start_resources(time)),
+        SearchMethod::Dfs => {
+            // robots that are needed at most, one robot can be built each minute
+            let limits = Resources {
+                ore_robot_num: *[blueprint.ore_robot_cost,
+                    blueprint.clay_robot_cost,
+                    blueprint.obs_robot_cost_ore,
+                    blueprint.geode_robot_cost_ore].iter().max().unwrap(),
+                clay_robot_num: blueprint.obs_robot_cost_clay,
+                obs_robot_num: blueprint.geode_robot_cost_obs,
+                ..start_resources(time)
+            };
+            let mut search = GeodeSearch { geodes: 0, states: 0, plan: Some(vec![BuildPlan::Nothing; time as usize]) };
+            try_blueprint_dfs(blueprint, &start_resources(time), &limits, &mut vec![], &mut search);
+            search
+        }
+    }
+}
+
+pub fn parse(lines: &[String]) -> Result<Vec<Blueprint>, Error> {
+    let re = Regex::new(concat!(
+        r"^Blueprint (\d+): Each ore robot costs (\d+) ore\. Each clay robot costs (\d+) ore\. ",
+        r"Each obsidian robot costs (\d+) ore and (\d+) clay\. Each geode robot costs (\d+) ore and (\d+) obsidian\.$"
+    )).unwrap();
+    let mut blueprints = vec![];
+    for (y, line) in lines.iter().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+        let digits = re.captures(line.trim()).ok_or_else(|| Error::parse(DAY, y, "not a valid blueprint"))?;
+        let id: u16 = parse_number(DAY, y, &digits[1])?;
+        let ore_robot_cost: u16 = parse_number(DAY, y, &digits[2])?;
+        let clay_robot_cost: u16 = parse_number(DAY, y, &digits[3])?;
+        let obs_robot_cost_ore: u16 = parse_number(DAY, y, &digits[4])?;
+        let obs_robot_cost_clay: u16 = parse_number(DAY, y, &digits[5])?;
+        let geode_robot_cost_ore: u16 = parse_number(DAY, y, &digits[6])?;
+        let geode_robot_cost_obs: u16 = parse_number(DAY, y, &digits[7])?;
+
+        blueprints.push(Blueprint {
+            id,
+            ore_robot_cost,
+            clay_robot_cost,
+            obs_robot_cost_ore,
+            obs_robot_cost_clay,
+            geode_robot_cost_ore,
+            geode_robot_cost_obs,
+        });
+    }
+    Ok(blueprints)
+}
+
+fn start_resources(time: u16) -> Resources {
+    Resources {
+        ore_robot_num: 1,
+        ore_num: 0,
+        clay_robot_num: 0,
+        clay_num: 0,
+        obs_robot_num: 0,
+        obs_num: 0,
+        geode_robot_num: 0,
+        geode_num: 0,
+        time,
+    }
+}
+
+fn robot_name(plan: &BuildPlan) -> &'static str {
+    match plan {
+        BuildPlan::Nothing => "",
+        BuildPlan::BuildOreRobot => "ore-collecting robot",
+        BuildPlan::BuildClayRobot => "clay-collecting robot",
+        BuildPlan::BuildObsidianRobot => "obsidian-collecting robot",
+        BuildPlan::BuildGeodeRobot => "geode-cracking robot",
+    }
+}
+
+fn robot_with_article(plan: &BuildPlan) -> String {
+    let article = if *plan == BuildPlan::BuildOreRobot || *plan == BuildPlan::BuildObsidianRobot { "an" } else { "a" };
+    format!("{} {}", article, robot_name(plan))
+}
+
+fn plural(num: u16, word: &str) -> String {
+    if num == 1 { format!("1 {}", word) } else { format!("{} {}s", num, word) }
+}
+
+fn collect_line(robots: u16, robot: &str, verb: &str, collected: String, total: String) -> String {
+    let verb = if robots == 1 { format!("{}s", verb) } else { verb.to_string() };
+    format!("{} {} {}; you now have {}.\n", plural(robots, robot), verb, collected, total)
+}
+
+// what happens minute by minute like in the puzzle text
+// fails if the plan builds a robot that can not be paid
+pub fn explain_plan(blueprint: &Blueprint, plan: &[BuildPlan]) -> Result<String, String> {
+    let mut r = start_resources(plan.len() as u16);
+    let mut minutes = vec![];
+    for (minute, build) in plan.iter().enumerate() {
+        let mut text = format!("== Minute {} ==\n", minute + 1);
+        let (ore, clay, obs) = robot_cost(blueprint, build);
+        if *build != BuildPlan::Nothing {
+            if r.ore_num < ore || r.clay_num < clay || r.obs_num < obs {
+                return Err(format!("minute {}: not enough to build {}", minute + 1, robot_with_article(build)));
+            }
+            let mut spend = vec![format!("{} ore", ore)];
+            if clay > 0 {
+                spend.push(format!("{} clay", clay));
+            }
+            if obs > 0 {
+                spend.push(format!("{} obsidian", obs));
+            }
+            text += &format!("Spend {} to start building {}.\n", spend.join(" and "), robot_with_article(build));
+        }
+        let r_new = build_robots(blueprint, &r, build, r.time - 1);
+        if r.ore_robot_num > 0 {
+            text += &collect_line(r.ore_robot_num, "ore-collecting robot", "collect", format!("{} ore", r.ore_robot_num), format!("{} ore", r_new.ore_num));
+        }
+        if r.clay_robot_num > 0 {
+            text += &collect_line(r.clay_robot_num, "clay-collecting robot", "collect", format!("{} clay", r.clay_robot_num), format!("{} clay", r_new.clay_num));
+        }
+        if r.obs_robot_num > 0 {
+            text += &collect_line(r.obs_robot_num, "obsidian-collecting robot", "collect",
+                                  format!("{} obsidian", r.obs_robot_num), format!("{} obsidian", r_new.obs_num));
+        }
+        if r.geode_robot_num > 0 {
+            text += &collect_line(r.geode_robot_num, "geode-cracking robot", "crack",
+                                  plural(r.geode_robot_num, "geode"), plural(r_new.geode_num, "open geode"));
+        }
+        let robots = match build {
+            BuildPlan::Nothing => 0,
+            BuildPlan::BuildOreRobot => r_new.ore_robot_num,
+            BuildPlan::BuildClayRobot => r_new.clay_robot_num,
+            BuildPlan::BuildObsidianRobot => r_new.obs_robot_num,
+            BuildPlan::BuildGeodeRobot => r_new.geode_robot_num,
+        };
+        if robots > 0 {
+            text += &format!("The new {} is ready; you now have {} of them.\n", robot_name(build), robots);
+        }
+        minutes.push(text);
+        r = r_new;
+    }
+    Ok(minutes.join("\n"))
+}
+
+// one thread per core
+pub fn default_threads() -> usize {
+    thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+}
+
+// the most geodes every blueprint can open, in the order of the blueprints
+// the threads take the next blueprint that is left until all are done
+pub fn max_geodes(blueprints: &[Blueprint], time: u16, threads: usize) -> Vec<u16> {
+    let threads = threads.clamp(1, blueprints.len().max(1));
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(vec![0; blueprints.len()]);
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| loop {
+                let idx = next.fetch_add(1, Ordering::Relaxed);
+                let Some(bp) = blueprints.get(idx) else {
+                    break;
+                };
+                let max = search_blueprint(bp, time, SearchMethod::Dfs).geodes;
+                results.lock().unwrap()[idx] = max;
+            });
+        }
+    });
+    results.into_inner().unwrap()
+}
+
+pub fn quality_level_sum(blueprints: &[Blueprint], threads: usize) -> u64 {
+    let max_list = max_geodes(blueprints, 24, threads);
+    blueprints.iter().zip(max_list).map(|(bp, max)| max as u64 * bp.id as u64).sum()
+}
+
+pub fn geode_product(blueprints: &[Blueprint], threads: usize) -> u64 {
+    let blueprints = &blueprints[..blueprints.len().min(3)];
+    max_geodes(blueprints, 32, threads).iter().map(|max| *max as u64).product()
+}
+
+pub fn part1(blueprints: &[Blueprint], threads: usize) -> u64 {
+    // 978
+    quality_level_sum(blueprints, threads)
+}
+
+pub fn part2(blueprints: &[Blueprint], threads: usize) -> u64 {
+    // 15939
+    geode_product(blueprints, threads)
+}
+
+// the blueprints with the threads to search them on
+#[derive(Debug, Clone)]
+pub struct Blueprints {
+    pub blueprints: Vec<Blueprint>,
+    pub threads: usize,
+}
+
+pub struct Day19;
+
+impl Solver for Day19 {
+    type Input = Blueprints;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, Error> {
+        Ok(Blueprints { blueprints: parse(lines)?, threads: default_threads() })
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, Error> {
+        Ok(part1(&input.blueprints, input.threads).to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, Error> {
+        Ok(part2(&input.blueprints, input.threads).to_string())
+    }
+
+    fn configure(input: &mut Self::Input, options: &Options) {
+        if let Some(threads) = options.threads {
+            input.threads = threads;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solver::{Options, Solver};
+    use super::{explain_plan, geode_product, max_geodes, parse, part1, part2, search_blueprint, BuildPlan, Day19, SearchMethod};
+
+    #[test]
+    fn it_works() {
+        let lines = ["Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.",
+                         "Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian."].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        let input = parse(&lines).unwrap();
+
+        let result = part1(&input, 2);
+        assert_eq!(result, 33);
+        let mut blueprints = Day19::parse(&lines).unwrap();
+        Day19::configure(&mut blueprints, &Options { threads: Some(1) });
+        assert_eq!(blueprints.threads, 1);
+        assert_eq!(Day19::part1(&blueprints), Ok("33".to_string()));
+        assert_eq!(max_geodes(&input, 24, 1), vec![9, 12]);
+        assert_eq!(max_geodes(&input, 24, 8), vec![9, 12]);
+        let bfs = search_blueprint(&input[0], 24, SearchMethod::Bfs);
+        let dfs = search_blueprint(&input[0], 24, SearchMethod::Dfs);
+        assert_eq!(bfs.geodes, dfs.geodes);
+        assert!(dfs.states < bfs.states);
+
+        assert_eq!(bfs.plan, None);
+        let dfs_plan = dfs.plan.unwrap();
+        assert_eq!(dfs_plan.len(), 24);
+        let text = explain_plan(&input[0], &dfs_plan).unwrap();
+        assert!(text.starts_with("== Minute 1 ==\n1 ore-collecting robot collects 1 ore; you now have 1 ore.\n\n== Minute 2 ==\n"));
+        assert!(text.ends_with("2 geode-cracking robots crack 2 geodes; you now have 9 open geodes.\n"));
+        // the start of the plan in the puzzle text
+        let mut plan = vec![BuildPlan::Nothing, BuildPlan::Nothing, BuildPlan::BuildClayRobot];
+        assert!(explain_plan(&input[0], &plan).unwrap().ends_with(concat!(
+            "== Minute 3 ==\n",
+            "Spend 2 ore to start building a clay-collecting robot.\n",
+            "1 ore-collecting robot collects 1 ore; you now have 1 ore.\n",
+            "The new clay-collecting robot is ready; you now have 1 of them.\n")));
+        plan.push(BuildPlan::BuildObsidianRobot);
+        assert_eq!(explain_plan(&input[0], &plan).unwrap_err(), "minute 4: not enough to build an obsidian-collecting robot");
+        let plan = [BuildPlan::BuildClayRobot];
+        assert_eq!(explain_plan(&input[0], &plan).unwrap_err(), "minute 1: not enough to build a clay-collecting robot");
+        let plan = [BuildPlan::BuildGeodeRobot];
+        assert_eq!(explain_plan(&input[0], &plan).unwrap_err(), "minute 1: not enough to build a geode-cracking robot");
+        let result = part2(&input, 2);
+        assert_eq!(result, 56 * 62);
+
+        // cheap robots crack far more geodes than a u16 product can hold
+        let cheap = (1..4)
+            .map(|id| format!("Blueprint {}: Each ore robot costs 1 ore. Each clay robot costs 1 ore. Each obsidian robot costs 1 ore and 1 clay. Each geode robot costs 1 ore and 1 obsidian.", id))
+            .collect::<Vec<_>>();
+        let cheap = parse(&cheap).unwrap();
+        assert_eq!(geode_product(&cheap, 3), 351 * 351 * 351);
+    }
+}
@@ -0,0 +1,159 @@
+use crate::solver::Solver;
+use crate::error::{get_field, Error};
+
+const DAY: usize = 2;
+
+#[derive(Debug, Clone)]
+pub struct Round {
+    pub opponent: String,
+    pub player: String,
+}
+
+pub fn parse(lines: &[String]) -> Result<Vec<Round>, Error> {
+    let mut rounds = vec![];
+    for (y, line) in lines.iter().enumerate() {
+        let round: Vec<_> = line.split_whitespace().collect();
+        let opponent = get_field(DAY, y, &round, 0)?;
+        let player = get_field(DAY, y, &round, 1)?;
+        if !["A", "B", "C"].contains(&opponent) {
+            return Err(Error::parse(DAY, y, &format!("unexpected opponent '{}'", opponent)));
+        }
+        if !["X", "Y", "Z"].contains(&player) {
+            return Err(Error::parse(DAY, y, &format!("unexpected player '{}'", player)));
+        }
+        rounds.push(Round {
+            opponent: opponent.to_string(),
+            player: player.to_string(),
+        });
+    }
+    Ok(rounds)
+}
+
+fn need_score(player: &str) -> u8 {
+    match player {
+        "X" => 0,
+        "Y" => 3,
+        "Z" => 6,
+        _ => 0
+    }
+}
+
+fn match_shape(opponent: &str, need_score: u8) -> &str {
+    match opponent {
+        "A" => match need_score {
+            0 => "C",
+            3 => "A",
+            6 => "B",
+            _ => ""
+        },
+        "B" => match need_score {
+            0 => "A",
+            3 => "B",
+            6 => "C",
+            _ => ""
+        }
+        "C" => match need_score {
+            0 => "B",
+            3 => "C",
+            6 => "A",
+            _ => ""
+        }
+        _ => ""
+    }
+}
+
+fn shape_score(shape: &str) -> u8 {
+    match shape {
+        "A" => 1,
+        "B" => 2,
+        "C" => 3,
+        _ => 0
+    }
+}
+
+pub fn part2(rounds: &[Round]) -> u32 {
+    // 11186
+    let mut sum = 0u32;
+    for round in rounds.iter() {
+        let opponent = round.opponent.as_str();
+        let player_result = round.player.as_str();
+
+        let need_score = need_score(player_result);
+        let player_shape = match_shape(opponent, need_score);
+        let shape_score = shape_score(player_shape);
+        sum += (shape_score + need_score) as u32;
+    }
+    sum
+}
+
+fn score(opponent: &str, player: &str) -> u8 {
+    match opponent {
+        "A" => match player {
+            "X" => 3 + 1,
+            "Y" => 6 + 2,
+            "Z" => 3,
+            _ => 0
+        },
+        "B" => match player {
+            "X" => 1,
+            "Y" => 3 + 2,
+            "Z" => 6 + 3,
+            _ => 0
+        }
+        "C" => match player {
+            "X" => 6 + 1,
+            "Y" => 2,
+            "Z" => 3 + 3,
+            _ => 0
+        }
+        _ => 0
+    }
+}
+
+pub fn part1(rounds: &[Round]) -> u32 {
+    // 11906
+    let mut sum = 0u32;
+    for round in rounds.iter() {
+        let opponent = round.opponent.as_str();
+        let player = round.player.as_str();
+        let score = score(opponent, player);
+        sum += score as u32;
+    }
+    sum
+}
+
+pub struct Day2;
+
+impl Solver for Day2 {
+    type Input = Vec<Round>;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, Error> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, Error> {
+        Ok(part1(input).to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, Error> {
+        Ok(part2(input).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let lines = ["A Y",
+                         "B X",
+                         "C Z"].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let input = parse(&lines).unwrap();
+
+        let result = part1(&input);
+        assert_eq!(result, 15);
+        let result = part2(&input);
+        assert_eq!(result, 12);
+    }
+}
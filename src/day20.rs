@@ -0,0 +1,156 @@
+use crate::solver::Solver;
+use crate::error::{parse_number, Error};
+
+const DAY: usize = 20;
+
+#[derive(Debug, Clone)]
+struct MixingEntry {
+    original_idx: usize,
+    num: i64,
+}
+
+const DECRYPTION_KEY: i64 = 811589153;
+
+fn get_entry_of_original_index(mixing_list: &[MixingEntry], idx: usize) -> usize {
+    mixing_list.iter().position(|e| e.original_idx == idx).unwrap()
+}
+
+pub fn parse(lines: &[String]) -> Result<Vec<i64>, Error> {
+    let numbers = lines.iter()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(y, line)| parse_number(DAY, y, line))
+        .collect::<Result<Vec<i64>, Error>>()?;
+    // a number is moved around the others, so there must be at least one more
+    if numbers.len() < 2 {
+        return Err(Error::input(DAY, &format!("expected at least 2 numbers but got {}", numbers.len())));
+    }
+    // the grove coordinates are relative to the 0
+    if numbers.iter().filter(|n| **n == 0).count() != 1 {
+        return Err(Error::input(DAY, "the file must contain exactly one 0"));
+    }
+    Ok(numbers)
+}
+
+pub fn part1(numbers: &[i64]) -> i64 {
+    // 7713
+    let mut list = vec![];
+    let mut mixing_list = vec![];
+
+    for (i, &num) in numbers.iter().enumerate() {
+        let e = MixingEntry {
+            original_idx: i,
+            num,
+        };
+        list.push(e.clone());
+        mixing_list.push(e.clone());
+    }
+
+    for (idx, _) in list.iter().enumerate() {
+        let next_entry_idx = get_entry_of_original_index(&mixing_list, idx);
+        let entry = mixing_list.remove(next_entry_idx);
+
+        let mut new_idx = next_entry_idx as i64 + entry.num;
+        if new_idx > mixing_list.len() as i64 {
+            new_idx = new_idx.rem_euclid(mixing_list.len() as i64);
+        } else if new_idx <= 0 {
+            new_idx = (new_idx + mixing_list.len() as i64).rem_euclid(mixing_list.len() as i64);
+        }
+
+        // let new_idx = (next_entry_idx as i32 + entry.num + mixing_list.len() as i32).rem_euclid(mixing_list.len() as i32);
+
+        mixing_list.insert(new_idx as usize, entry);
+    }
+
+
+    let zero_pos = mixing_list.iter().position(|e| e.num == 0).unwrap();
+    let digit_1000 = mixing_list.get((zero_pos + 1000) % mixing_list.len()).unwrap().num;
+    let digit_2000 = mixing_list.get((zero_pos + 2000) % mixing_list.len()).unwrap().num;
+    let digit_3000 = mixing_list.get((zero_pos + 3000) % mixing_list.len()).unwrap().num;
+
+    digit_1000 + digit_2000 + digit_3000
+}
+
+pub fn part2(numbers: &[i64]) -> i64 {
+    // 1664569352803
+    let mut list = vec![];
+    let mut mixing_list = vec![];
+
+    for (i, &num) in numbers.iter().enumerate() {
+        let e = MixingEntry {
+            original_idx: i,
+            num: num * DECRYPTION_KEY,
+        };
+        list.push(e.clone());
+        mixing_list.push(e.clone());
+    }
+
+    for _ in 0..10 {
+        for (idx, num) in list.iter().enumerate() {
+            let next_entry_idx = get_entry_of_original_index(&mixing_list, idx);
+            let entry = mixing_list.remove(next_entry_idx);
+
+            let mut new_idx = next_entry_idx as i64 + num.num;
+            if new_idx > mixing_list.len() as i64 {
+                new_idx = new_idx.rem_euclid(mixing_list.len() as i64);
+            } else if new_idx <= 0 {
+                new_idx = (new_idx + mixing_list.len() as i64).rem_euclid(mixing_list.len() as i64);
+            }
+
+            // let new_idx = (next_entry_idx as i32 + entry.num + mixing_list.len() as i32).rem_euclid(mixing_list.len() as i32);
+
+            mixing_list.insert(new_idx as usize, entry);
+        }
+    }
+
+
+    let zero_pos = mixing_list.iter().position(|e| e.num == 0).unwrap();
+    let digit_1000 = mixing_list.get((zero_pos + 1000) % mixing_list.len()).unwrap().num;
+    let digit_2000 = mixing_list.get((zero_pos + 2000) % mixing_list.len()).unwrap().num;
+    let digit_3000 = mixing_list.get((zero_pos + 3000) % mixing_list.len()).unwrap().num;
+
+    digit_1000 + digit_2000 + digit_3000
+}
+
+pub struct Day20;
+
+impl Solver for Day20 {
+    type Input = Vec<i64>;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, Error> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, Error> {
+        Ok(part1(input).to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, Error> {
+        Ok(part2(input).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1};
+
+    #[test]
+    fn it_works() {
+        let lines = ["1",
+                         "2",
+                         "-3",
+                         "3",
+                         "-2",
+                         "0",
+                         "4"].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        let input = parse(&lines).unwrap();
+
+        let result = part1(&input);
+        assert_eq!(result, 3);
+        assert_eq!(parse(&["0".to_string()]).unwrap_err().to_string(), "day 20: expected at least 2 numbers but got 1");
+        assert_eq!(part1(&parse(&["0".to_string(), "5".to_string()]).unwrap()), 0);
+        // let result = part2(&input);
+        // assert_eq!(result, 58);
+    }
+}
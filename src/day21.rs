@@ -0,0 +1,526 @@
+use std::cmp::PartialEq;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use crate::json_string;
+use crate::search::bfs;
+use crate::solver::Solver;
+use crate::error::{parse_number, Error};
+
+const DAY: usize = 21;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Operation {
+    Plus,
+    Minus,
+    Mul,
+    Div,
+    Nothing,
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operation::Plus => write!(f, "+"),
+            Operation::Minus => write!(f, "-"),
+            Operation::Mul => write!(f, "*"),
+            Operation::Div => write!(f, "/"),
+            Operation::Nothing => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    pub name: String,
+    pub op: Operation,
+    // the number of a monkey without an operation
+    pub value: Option<i64>,
+    pub input1: Option<String>,
+    pub input2: Option<String>,
+}
+
+impl Monkey {
+    // the number this monkey yells when its inputs yell a and b,
+    // fails on an overflow and on a division that does not come out even
+    fn eval(&self, a: i64, b: i64) -> Result<i64, String> {
+        let value = match self.op {
+            Operation::Plus => a.checked_add(b),
+            Operation::Minus => a.checked_sub(b),
+            Operation::Mul => a.checked_mul(b),
+            Operation::Div => {
+                if b == 0 {
+                    return Err(format!("{}: {} / 0 is a division by 0", self.name, a));
+                }
+                if a % b != 0 {
+                    return Err(format!("{}: {} / {} is not a whole number", self.name, a, b));
+                }
+                a.checked_div(b)
+            }
+            Operation::Nothing => self.value,
+        };
+        value.ok_or_else(|| format!("{}: {} {} {} overflows", self.name, a, self.op, b))
+    }
+
+    fn inputs(&self) -> Vec<&String> {
+        self.input1.iter().chain(self.input2.iter()).collect()
+    }
+}
+
+// all monkeys of the input, every name is defined once,
+// every monkey that is waited for exists and no monkey waits for itself
+#[derive(Debug, Clone)]
+pub struct MonkeyGraph {
+    pub monkeys: Vec<Monkey>,
+    index: HashMap<String, usize>,
+    // every monkey comes after the monkeys it waits for
+    order: Vec<usize>,
+}
+
+impl MonkeyGraph {
+    pub fn get(&self, name: &str) -> Option<&Monkey> {
+        self.index.get(name).map(|idx| &self.monkeys[*idx])
+    }
+
+    // the number every monkey yells, in the order of monkeys
+    pub fn evaluate(&self) -> Result<Vec<i64>, String> {
+        let mut values = vec![0; self.monkeys.len()];
+        for idx in self.order.iter() {
+            let m = &self.monkeys[*idx];
+            let inputs = m.inputs().iter().map(|name| values[self.index[*name]]).collect::<Vec<_>>();
+            values[*idx] = match m.value {
+                Some(value) => value,
+                None => m.eval(inputs[0], inputs[1])?,
+            };
+        }
+        Ok(values)
+    }
+
+    pub fn value_of(&self, name: &str) -> Result<i64, String> {
+        let idx = *self.index.get(name).ok_or_else(|| format!("there is no monkey {}", name))?;
+        Ok(self.evaluate()?[idx])
+    }
+}
+
+// depth first, path holds the monkeys that wait for the current one
+fn visit(idx: usize, inputs: &[Vec<usize>], done: &mut [bool], path: &mut Vec<usize>, order: &mut Vec<usize>) -> Result<(), Vec<usize>> {
+    if done[idx] {
+        return Ok(());
+    }
+    if let Some(start) = path.iter().position(|i| *i == idx) {
+        let mut cycle = path[start..].to_vec();
+        cycle.push(idx);
+        return Err(cycle);
+    }
+    path.push(idx);
+    for input in inputs[idx].iter() {
+        visit(*input, inputs, done, path, order)?;
+    }
+    path.pop();
+    done[idx] = true;
+    order.push(idx);
+    Ok(())
+}
+
+pub fn parse(lines: &[String]) -> Result<MonkeyGraph, Error> {
+    let mut monkeys = vec![];
+    let mut index = HashMap::new();
+    let mut line_of = vec![];
+    for (y, line) in lines.iter().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+        let (monkey, job) = line.split_once(":").ok_or_else(|| Error::parse(DAY, y, "expected 'name: job'"))?;
+        let job_parts = job.split_whitespace().collect::<Vec<_>>();
+        let m = match job_parts[..] {
+            [value] => Monkey {
+                name: monkey.to_string(),
+                op: Operation::Nothing,
+                value: Some(parse_number(DAY, y, value)?),
+                input1: None,
+                input2: None,
+            },
+            [m1_name, op, m2_name] => {
+                let op = match op {
+                    "+" => Operation::Plus,
+                    "-" => Operation::Minus,
+                    "*" => Operation::Mul,
+                    "/" => Operation::Div,
+                    _ => return Err(Error::parse(DAY, y, &format!("unknown operation '{}'", op))),
+                };
+                Monkey {
+                    name: monkey.to_string(),
+                    op,
+                    value: None,
+                    input1: Some(m1_name.to_string()),
+                    input2: Some(m2_name.to_string()),
+                }
+            }
+            _ => return Err(Error::parse(DAY, y, "expected a number or 'name op name'")),
+        };
+        if index.insert(m.name.to_string(), monkeys.len()).is_some() {
+            return Err(Error::parse(DAY, y, &format!("duplicate monkey {}", m.name)));
+        }
+        monkeys.push(m);
+        line_of.push(y);
+    }
+
+    let mut inputs = vec![];
+    for (m, y) in monkeys.iter().zip(line_of) {
+        let mut ids = vec![];
+        for name in m.inputs() {
+            let id = index.get(name).ok_or_else(|| Error::parse(DAY, y, &format!("{} waits for unknown monkey {}", m.name, name)))?;
+            ids.push(*id);
+        }
+        inputs.push(ids);
+    }
+    let mut done = vec![false; monkeys.len()];
+    let mut order = vec![];
+    for idx in 0..monkeys.len() {
+        visit(idx, &inputs, &mut done, &mut vec![], &mut order).map_err(|cycle| {
+            let names = cycle.iter().map(|idx| monkeys[*idx].name.as_str()).collect::<Vec<_>>();
+            Error::input(DAY, &format!("monkeys wait for each other: {}", names.join(" -> ")))
+        })?;
+    }
+
+    if !monkeys.iter().any(|m| m.name == "root" && m.op != Operation::Nothing) {
+        return Err(Error::input(DAY, "no root monkey with an operation"));
+    }
+    Ok(MonkeyGraph { monkeys, index, order })
+}
+
+pub fn part1(graph: &MonkeyGraph) -> Result<i64, String> {
+    // 63119856257960
+    graph.value_of("root")
+}
+
+// the monkeys from root down to humn, empty if root does not wait for humn
+pub fn humn_path(graph: &MonkeyGraph) -> Vec<String> {
+    let successors = |name: &String| graph.get(name).map(|m| m.inputs().into_iter().cloned().collect::<Vec<_>>()).unwrap_or_default();
+    bfs("root".to_string(), successors, |name| name == "humn")
+        .map(|path| path.nodes)
+        .unwrap_or_default()
+}
+
+// graphviz with the operation or the number of every monkey,
+// an edge points to a monkey that is waited for, the way from root to humn is red
+pub fn to_dot(graph: &MonkeyGraph) -> String {
+    let path = humn_path(graph);
+    let highlight = "color=red penwidth=2";
+    let mut dot = "digraph monkeys {\n".to_string();
+    for m in graph.monkeys.iter() {
+        let job = match m.value {
+            Some(value) => value.to_string(),
+            None => m.op.to_string(),
+        };
+        let style = if path.contains(&m.name) { format!(" {}", highlight) } else { String::new() };
+        dot += &format!("    \"{}\" [label=\"{}\\n{}\"{}];\n", m.name, m.name, job, style);
+    }
+    for m in graph.monkeys.iter() {
+        for input in m.inputs() {
+            let on_path = path.windows(2).any(|w| w[0] == m.name && w[1] == *input);
+            let style = if on_path { format!(" [{}]", highlight) } else { String::new() };
+            dot += &format!("    \"{}\" -> \"{}\"{};\n", m.name, input, style);
+        }
+    }
+    dot += "}\n";
+    dot
+}
+
+// one object per monkey with either its number or its operation and inputs
+pub fn to_json(graph: &MonkeyGraph) -> String {
+    let path = humn_path(graph);
+    let monkeys = graph.monkeys.iter().map(|m| {
+        let job = match m.value {
+            Some(value) => format!("\"value\": {}", value),
+            None => {
+                let inputs = m.inputs().iter().map(|name| json_string(name)).collect::<Vec<_>>();
+                format!("\"op\": \"{}\", \"inputs\": [{}]", m.op, inputs.join(", "))
+            }
+        };
+        format!("    {{\"name\": {}, {}, \"humn_path\": {}}}", json_string(&m.name), job, path.contains(&m.name))
+    }).collect::<Vec<_>>();
+    let path = path.iter().map(|name| json_string(name)).collect::<Vec<_>>();
+    format!("{{\n  \"humn_path\": [{}],\n  \"monkeys\": [\n{}\n  ]\n}}\n", path.join(", "), monkeys.join(",\n"))
+}
+
+// an exact fraction, always reduced and with a positive denominator
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Ratio {
+    pub num: i128,
+    pub den: i128,
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    if b == 0 { a.abs() } else { gcd(b, a % b) }
+}
+
+// the checked_ functions return None on an overflow or a division by 0
+impl Ratio {
+    pub fn new(num: i128, den: i128) -> Result<Ratio, String> {
+        if den == 0 {
+            return Err(format!("denominator of {}/{} is 0", num, den));
+        }
+        let g = gcd(num, den) * den.signum();
+        Ok(Ratio { num: num / g, den: den / g })
+    }
+
+    pub fn integer(n: i128) -> Ratio {
+        Ratio { num: n, den: 1 }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.num == 0
+    }
+
+    pub fn checked_add(self, other: Ratio) -> Option<Ratio> {
+        let num = self.num.checked_mul(other.den)?.checked_add(other.num.checked_mul(self.den)?)?;
+        Ratio::new(num, self.den.checked_mul(other.den)?).ok()
+    }
+
+    pub fn checked_sub(self, other: Ratio) -> Option<Ratio> {
+        self.checked_add(Ratio { num: other.num.checked_neg()?, den: other.den })
+    }
+
+    pub fn checked_mul(self, other: Ratio) -> Option<Ratio> {
+        Ratio::new(self.num.checked_mul(other.num)?, self.den.checked_mul(other.den)?).ok()
+    }
+
+    pub fn checked_div(self, other: Ratio) -> Option<Ratio> {
+        if other.is_zero() {
+            return None;
+        }
+        Ratio::new(self.num.checked_mul(other.den)?, self.den.checked_mul(other.num)?).ok()
+    }
+}
+
+impl Display for Ratio {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+// the job of a monkey together with the jobs of the monkeys it waits for,
+// humn is the unknown
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    Number(i64),
+    Humn,
+    Op {
+        name: String,
+        op: Operation,
+        left: Box<Expr>,
+        right: Box<Expr>,
+    },
+}
+
+// panics for a name that is not in the graph
+pub fn build_expr(graph: &MonkeyGraph, name: &str) -> Expr {
+    if name == "humn" {
+        return Expr::Humn;
+    }
+    let m = graph.get(name).unwrap();
+    match m.value {
+        Some(value) => Expr::Number(value),
+        None => Expr::Op {
+            name: name.to_string(),
+            op: m.op.clone(),
+            left: Box::new(build_expr(graph, m.input1.as_ref().unwrap())),
+            right: Box::new(build_expr(graph, m.input2.as_ref().unwrap())),
+        },
+    }
+}
+
+// a * humn + b
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Linear {
+    pub a: Ratio,
+    pub b: Ratio,
+}
+
+impl Linear {
+    pub fn has_humn(&self) -> bool {
+        !self.a.is_zero()
+    }
+}
+
+impl Display for Linear {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} * humn + {}", self.a, self.b)
+    }
+}
+
+// fails where humn would end up squared or below a division
+pub fn simplify(expr: &Expr) -> Result<Linear, String> {
+    match expr {
+        Expr::Number(n) => Ok(Linear { a: Ratio::integer(0), b: Ratio::integer(*n as i128) }),
+        Expr::Humn => Ok(Linear { a: Ratio::integer(1), b: Ratio::integer(0) }),
+        Expr::Op { name, op, left, right } => {
+            let l = simplify(left)?;
+            let r = simplify(right)?;
+            let overflow = || format!("{} overflows", name);
+            match op {
+                Operation::Plus => Ok(Linear {
+                    a: l.a.checked_add(r.a).ok_or_else(overflow)?,
+                    b: l.b.checked_add(r.b).ok_or_else(overflow)?,
+                }),
+                Operation::Minus => Ok(Linear {
+                    a: l.a.checked_sub(r.a).ok_or_else(overflow)?,
+                    b: l.b.checked_sub(r.b).ok_or_else(overflow)?,
+                }),
+                Operation::Mul => {
+                    if l.has_humn() && r.has_humn() {
+                        return Err(format!("{} multiplies humn with humn, the equation is not linear", name));
+                    }
+                    let a = l.a.checked_mul(r.b).and_then(|x| x.checked_add(r.a.checked_mul(l.b)?));
+                    Ok(Linear { a: a.ok_or_else(overflow)?, b: l.b.checked_mul(r.b).ok_or_else(overflow)? })
+                }
+                Operation::Div => {
+                    if r.has_humn() {
+                        return Err(format!("{} divides by humn, the equation is not linear", name));
+                    }
+                    if r.b.is_zero() {
+                        return Err(format!("{} divides by 0", name));
+                    }
+                    Ok(Linear {
+                        a: l.a.checked_div(r.b).ok_or_else(overflow)?,
+                        b: l.b.checked_div(r.b).ok_or_else(overflow)?,
+                    })
+                }
+                Operation::Nothing => unreachable!(),
+            }
+        }
+    }
+}
+
+// the value humn has to yell so that both monkeys of root yell the same
+pub fn solve_humn(graph: &MonkeyGraph) -> Result<Ratio, String> {
+    if graph.get("humn").is_none() {
+        return Err("there is no monkey humn".to_string());
+    }
+    let Expr::Op { left, right, .. } = build_expr(graph, "root") else {
+        return Err("root has no operation".to_string());
+    };
+    let l = simplify(&left)?;
+    let r = simplify(&right)?;
+    let (x, y) = match (l.has_humn(), r.has_humn()) {
+        (true, true) => return Err("humn appears on both sides of root".to_string()),
+        (false, false) => return Err("humn does not appear below root".to_string()),
+        (true, false) => (l, r),
+        (false, true) => (r, l),
+    };
+    // a * humn + b = c
+    y.b.checked_sub(x.b).and_then(|c| c.checked_div(x.a)).ok_or_else(|| "root overflows".to_string())
+}
+
+pub fn part2(graph: &MonkeyGraph) -> Result<i64, String> {
+    // 3006709232464
+    let humn = solve_humn(graph)?;
+    if humn.den != 1 {
+        return Err(format!("humn would have to yell {}", humn));
+    }
+    i64::try_from(humn.num).map_err(|_| format!("humn would have to yell {}", humn))
+}
+
+pub struct Day21;
+
+impl Solver for Day21 {
+    type Input = MonkeyGraph;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, Error> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, Error> {
+        part1(input).map(|root| root.to_string()).map_err(|e| Error::input(DAY, &e))
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, Error> {
+        part2(input).map(|humn| humn.to_string()).map_err(|e| Error::input(DAY, &e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_expr, humn_path, parse, part1, part2, simplify, solve_humn, to_dot, to_json, Ratio};
+
+    #[test]
+    fn it_works() {
+        let lines = vec!["root: pppw + sjmn",
+                         "dbpl: 5",
+                         "cczh: sllz + lgvd",
+                         "zczc: 2",
+                         "ptdq: humn - dvpt",
+                         "dvpt: 3",
+                         "lfqf: 4",
+                         "humn: 5",
+                         "ljgn: 2",
+                         "sjmn: drzm * dbpl",
+                         "sllz: 4",
+                         "pppw: cczh / lfqf",
+                         "lgvd: ljgn * ptdq",
+                         "drzm: hmdt - zczc",
+                         "hmdt: 32"].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        let input = parse(&lines).unwrap();
+
+        let result = part1(&input);
+        assert_eq!(result, Ok(152));
+        let result = part2(&input);
+        assert_eq!(result, Ok(301));
+
+        assert_eq!(humn_path(&input), vec!["root", "pppw", "cczh", "lgvd", "ptdq", "humn"]);
+        let dot = to_dot(&input);
+        assert!(dot.starts_with("digraph monkeys {\n    \"root\" [label=\"root\\n+\" color=red penwidth=2];\n    \"dbpl\" [label=\"dbpl\\n5\"];\n"));
+        assert!(dot.contains("    \"root\" -> \"pppw\" [color=red penwidth=2];\n    \"root\" -> \"sjmn\";\n"));
+        let json = to_json(&input);
+        assert!(json.starts_with("{\n  \"humn_path\": [\"root\", \"pppw\", \"cczh\", \"lgvd\", \"ptdq\", \"humn\"],\n  \"monkeys\": [\n"));
+        assert!(json.contains("    {\"name\": \"root\", \"op\": \"+\", \"inputs\": [\"pppw\", \"sjmn\"], \"humn_path\": true},\n"));
+        assert!(json.contains("    {\"name\": \"dbpl\", \"value\": 5, \"humn_path\": false},\n"));
+
+        // pppw is (4 + 2 * (humn - 3)) / 4
+        let pppw = simplify(&build_expr(&input, "pppw")).unwrap();
+        assert_eq!((pppw.a, pppw.b), (Ratio::new(1, 2).unwrap(), Ratio::new(-1, 2).unwrap()));
+
+        // the example with other monkeys in place of the first ones
+        let change = |more: &[&str]| {
+            let names = more.iter().map(|m| m.split(':').next().unwrap()).collect::<Vec<_>>();
+            let mut lines = lines.iter().filter(|l| !names.contains(&l.split(':').next().unwrap())).cloned().collect::<Vec<_>>();
+            lines.extend(more.iter().map(|s| s.to_string()));
+            parse(&lines)
+        };
+        assert_eq!(solve_humn(&change(&["root: pppw + lfqf"]).unwrap()), Ok(Ratio::integer(9)));
+        assert_eq!(solve_humn(&change(&["root: dbpl + ptdq"]).unwrap()), Ok(Ratio::integer(8)));
+        let input = change(&["root: lgvd + dvpt"]).unwrap();
+        assert_eq!(solve_humn(&input), Ratio::new(9, 2));
+        assert_eq!(Ratio::new(6, -4), Ok(Ratio { num: -3, den: 2 }));
+        assert_eq!(Ratio::new(1, 0), Err("denominator of 1/0 is 0".to_string()));
+        assert_eq!(part2(&input), Err("humn would have to yell 9/2".to_string()));
+        let e = solve_humn(&change(&["root: pppw + ptdq"]).unwrap()).unwrap_err();
+        assert_eq!(e, "humn appears on both sides of root");
+        let e = solve_humn(&change(&["root: sllz + dvpt"]).unwrap()).unwrap_err();
+        assert_eq!(e, "humn does not appear below root");
+        let e = solve_humn(&change(&["root: sqr + sllz", "sqr: ptdq * ptdq"]).unwrap()).unwrap_err();
+        assert_eq!(e, "sqr multiplies humn with humn, the equation is not linear");
+        let e = solve_humn(&change(&["root: inv + sllz", "inv: sllz / ptdq"]).unwrap()).unwrap_err();
+        assert_eq!(e, "inv divides by humn, the equation is not linear");
+
+        // checked arithmetic
+        let e = part1(&change(&["pppw: cczh / dvpt"]).unwrap()).unwrap_err();
+        assert_eq!(e, "pppw: 8 / 3 is not a whole number");
+        let e = part1(&change(&["hmdt: 9223372036854775807"]).unwrap()).unwrap_err();
+        assert_eq!(e, "sjmn: 9223372036854775805 * 5 overflows");
+
+        // a broken graph
+        let lines_with = |more: &str| lines.iter().cloned().chain([more.to_string()]).collect::<Vec<_>>();
+        let e = parse(&lines_with("hmdt: 3")).unwrap_err();
+        assert_eq!(e.to_string(), "day 21 line 16: duplicate monkey hmdt");
+        let e = parse(&lines_with("abcd: root * efgh")).unwrap_err();
+        assert_eq!(e.to_string(), "day 21 line 16: abcd waits for unknown monkey efgh");
+        let e = change(&["hmdt: drzm + dbpl"]).unwrap_err();
+        assert_eq!(e.to_string(), "day 21: monkeys wait for each other: drzm -> hmdt -> drzm");
+    }
+}
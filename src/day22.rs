@@ -0,0 +1,928 @@
+use regex::Regex;
+use std::cmp::{max, PartialEq};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::ops::Range;
+use crate::solver::Solver;
+use crate::error::{parse_number, Error};
+
+const DAY: usize = 22;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+fn get_direction_value(direction: &Direction) -> usize {
+    match direction {
+        Direction::Up => 3,
+        Direction::Down => 1,
+        Direction::Left => 2,
+        Direction::Right => 0,
+    }
+}
+#[derive(Debug, Clone)]
+pub enum Order {
+    Move(usize),
+    Right,
+    Left,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Tile {
+    Space,
+    Wall,
+}
+
+#[derive(Debug, Clone)]
+pub struct GridLine {
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct GridColumn {
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Board {
+    pub grid: HashMap<(usize, usize), Tile>,
+    // first and last x of the map per line
+    pub grid_lines: HashMap<usize, GridLine>,
+    // first and last y of the map per column
+    pub grid_columns: HashMap<usize, GridColumn>,
+    pub max_x: usize,
+    pub max_y: usize,
+    pub path: Vec<Order>,
+}
+
+// the map folded into a cube, faces are numbered in reading order of the net
+#[derive(Debug, Clone)]
+pub struct Cube {
+    pub faces: HashMap<usize, CubeFace>,
+    // the direction we walk on after crossing from one face to the other
+    pub edges: HashMap<(usize, usize), Direction>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CubeFace {
+    pub x_range: Range<usize>,
+    pub y_range: Range<usize>,
+    pub down_face_id: usize,
+    pub up_face_id: usize,
+    pub left_face_id: usize,
+    pub right_face_id: usize,
+}
+
+fn add_direction_clockwise(direction: &Direction) -> Direction {
+    match direction {
+        Direction::Left => Direction::Up,
+        Direction::Right => Direction::Down,
+        Direction::Up => Direction::Right,
+        Direction::Down => Direction::Left,
+    }
+}
+
+fn add_direction_counterclockwise(direction: &Direction) -> Direction {
+    match direction {
+        Direction::Left => Direction::Down,
+        Direction::Right => Direction::Up,
+        Direction::Up => Direction::Left,
+        Direction::Down => Direction::Right,
+    }
+}
+
+// a position the walk gets to that is not a tile of the map
+fn tile_at(grid: &HashMap<(usize, usize), Tile>, pos: (usize, usize)) -> Result<&Tile, String> {
+    grid.get(&pos).ok_or_else(|| format!("row {} column {} is not on the map", pos.1 + 1, pos.0 + 1))
+}
+
+fn get_possible_pos_in_direction(
+    grid: &HashMap<(usize, usize), Tile>,
+    grid_lines: &HashMap<usize, GridLine>,
+    grid_columns: &HashMap<usize, GridColumn>,
+    steps: usize,
+    pos: ((usize, usize), Direction),
+    mut trace: Option<&mut Vec<((usize, usize), Direction)>>,
+) -> Result<(usize, usize), String> {
+    let direction = pos.1;
+    let mut move_pos = pos.0;
+
+    match direction {
+        Direction::Up => {
+            for _ in 0..steps {
+                let old_pos = move_pos;
+                let grid_columns = grid_columns.get(&move_pos.0).unwrap();
+                if move_pos.1 == grid_columns.start {
+                    // wrap around
+                    move_pos = (move_pos.0, grid_columns.end);
+                } else {
+                    move_pos = (move_pos.0, move_pos.1 - 1);
+                }
+                if tile_at(grid, move_pos)? == &Tile::Wall {
+                    return Ok((old_pos.0, old_pos.1));
+                }
+                if let Some(trace) = trace.as_mut() {
+                    trace.push((move_pos, direction.clone()));
+                }
+            }
+        }
+        Direction::Down => {
+            for _ in 0..steps {
+                let old_pos = move_pos;
+                let grid_columns = grid_columns.get(&move_pos.0).unwrap();
+                if move_pos.1 == grid_columns.end {
+                    // wrap around
+                    move_pos = (move_pos.0, grid_columns.start);
+                } else {
+                    move_pos = (move_pos.0, move_pos.1 + 1);
+                }
+                if tile_at(grid, move_pos)? == &Tile::Wall {
+                    return Ok((old_pos.0, old_pos.1));
+                }
+                if let Some(trace) = trace.as_mut() {
+                    trace.push((move_pos, direction.clone()));
+                }
+            }
+        }
+        Direction::Left => {
+            for _ in 0..steps {
+                let old_pos = move_pos;
+                let grid_line = grid_lines.get(&move_pos.1).unwrap();
+                if move_pos.0 == grid_line.start {
+                    // wrap around
+                    move_pos = (grid_line.end, move_pos.1);
+                } else {
+                    move_pos = (move_pos.0 - 1, move_pos.1);
+                }
+                if tile_at(grid, move_pos)? == &Tile::Wall {
+                    return Ok((old_pos.0, old_pos.1));
+                }
+                if let Some(trace) = trace.as_mut() {
+                    trace.push((move_pos, direction.clone()));
+                }
+            }
+        }
+        Direction::Right => {
+            for _ in 0..steps {
+                let old_pos = move_pos;
+                let grid_line = grid_lines.get(&move_pos.1).unwrap();
+                if move_pos.0 == grid_line.end {
+                    // wrap around
+                    move_pos = (grid_line.start, move_pos.1);
+                } else {
+                    move_pos = (move_pos.0 + 1, move_pos.1);
+                }
+                if tile_at(grid, move_pos)? == &Tile::Wall {
+                    return Ok((old_pos.0, old_pos.1));
+                }
+                if let Some(trace) = trace.as_mut() {
+                    trace.push((move_pos, direction.clone()));
+                }
+            }
+        }
+    }
+    Ok(move_pos)
+}
+
+pub fn parse(lines: &[String]) -> Result<Board, Error> {
+    let mut path_list: Vec<Order> = vec![];
+    let mut grid: HashMap<(usize, usize), Tile> = HashMap::new();
+    let mut grid_lines: HashMap<usize, GridLine> = HashMap::new();
+    let mut grid_columns: HashMap<usize, GridColumn> = HashMap::new();
+    let mut max_x = 0;
+
+    let mut path_idx = 0;
+    for (y, line) in lines.iter().enumerate() {
+        if line.is_empty() {
+            path_idx = y + 1;
+            break;
+        }
+
+        let mut start_x: i32 = -1;
+        for (x, c) in line.chars().enumerate() {
+            let pos = (x, y);
+            if c == '#' {
+                if start_x == -1 {
+                    start_x = x as i32;
+                }
+                grid.insert(pos, Tile::Wall);
+            } else if c == '.' {
+                if start_x == -1 {
+                    start_x = x as i32;
+                }
+                grid.insert(pos, Tile::Space);
+            } else if c != ' ' {
+                return Err(Error::unexpected_char(DAY, y, x, c));
+            }
+        }
+        if start_x == -1 {
+            return Err(Error::parse(DAY, y, "line without any tile"));
+        }
+        // trailing spaces are not part of the map
+        let end_x = line.trim_end().len() as i32 - 1;
+        if line[start_x as usize..end_x as usize].contains(' ') {
+            return Err(Error::parse(DAY, y, "gap between the tiles"));
+        }
+        max_x = max(end_x, max_x);
+
+        let grid_line = GridLine {
+            start: start_x as usize,
+            end: end_x as usize,
+        };
+        grid_lines.insert(y, grid_line);
+    }
+    if path_idx == 0 || path_idx >= lines.len() {
+        return Err(Error::input(DAY, "missing the path below the map"));
+    }
+    if grid_lines.is_empty() {
+        return Err(Error::parse(DAY, 0, "the map is empty"));
+    }
+    let max_y = grid_lines.len() - 1;
+
+    for x in 0..max_x + 1 {
+        let mut start_y: i32 = -1;
+        let mut end_y: i32 = -1;
+        for y in 0..max_y + 1 {
+            let line = grid_lines.get(&y).unwrap();
+            if line.start <= x as usize && line.end >= x as usize {
+                if start_y == -1 {
+                    start_y = y as i32;
+                }
+            } else {
+                if start_y != -1 && end_y == -1 {
+                    end_y = (y - 1) as i32;
+                    break;
+                }
+            }
+        }
+        if start_y != -1 && end_y == -1 {
+            end_y = (grid_lines.len() - 1) as i32;
+        }
+        if start_y != -1 && end_y != -1 {
+            let r = GridColumn {
+                start: start_y as usize,
+                end: end_y as usize,
+            };
+            grid_columns.insert(x as usize, r);
+        }
+    }
+    // println!("{:?}", grid_lines);
+    // println!("{:?}", grid_columns);
+
+    let re = Regex::new(r"(\d+|L|R)").unwrap(); // \d means digit
+    let path = lines[path_idx].trim_end();
+    // println!("{}", path);
+    if let Some((x, c)) = path.chars().enumerate().find(|(_, c)| !c.is_ascii_digit() && *c != 'L' && *c != 'R') {
+        return Err(Error::unexpected_char(DAY, path_idx, x, c));
+    }
+
+    let moves = re.find_iter(path).collect::<Vec<_>>();
+    for m in moves {
+        let order = m.as_str();
+        match order {
+            "L" => path_list.push(Order::Left),
+            "R" => path_list.push(Order::Right),
+            _ => {
+                let num: usize = parse_number(DAY, path_idx, order)?;
+                path_list.push(Order::Move(num))
+            }
+        }
+    }
+    // println!("{:?}", path_list);
+
+    Ok(Board {
+        grid,
+        grid_lines,
+        grid_columns,
+        max_x: max_x as usize,
+        max_y,
+        path: path_list,
+    })
+}
+
+// where the walk ends or the first step off the map, trace gets every tile it visits with the facing after it,
+// turns repeat the tile
+// with a cube it walks on the folded cube like part 2 instead of wrapping around the map
+fn walk(
+    board: &Board,
+    cube: Option<&Cube>,
+    mut trace: Option<&mut Vec<((usize, usize), Direction)>>,
+) -> Result<((usize, usize), Direction), String> {
+    let mut current_pos = ((board.grid_lines.get(&0).unwrap().start, 0), Direction::Right);
+    if let Some(trace) = trace.as_mut() {
+        trace.push(current_pos.clone());
+    }
+
+    for order in board.path.iter() {
+        // println!("{:?} {:?}", order, current_pos);
+        match order {
+            Order::Move(steps) => match cube {
+                Some(cube) => {
+                    current_pos = get_possible_position_on_face(
+                        &board.grid,
+                        &cube.faces,
+                        &cube.edges,
+                        *steps,
+                        current_pos.0,
+                        &current_pos.1,
+                        trace.as_deref_mut(),
+                    )?;
+                }
+                None => {
+                    let new_pos = get_possible_pos_in_direction(
+                        &board.grid,
+                        &board.grid_lines,
+                        &board.grid_columns,
+                        *steps,
+                        current_pos.clone(),
+                        trace.as_deref_mut(),
+                    )?;
+                    current_pos = (new_pos, current_pos.1);
+                }
+            },
+            Order::Right => {
+                let d = add_direction_clockwise(&current_pos.1);
+                current_pos = (current_pos.0, d);
+                if let Some(trace) = trace.as_mut() {
+                    trace.push(current_pos.clone());
+                }
+            }
+            Order::Left => {
+                let d = add_direction_counterclockwise(&current_pos.1);
+                current_pos = (current_pos.0, d);
+                if let Some(trace) = trace.as_mut() {
+                    trace.push(current_pos.clone());
+                }
+            }
+        }
+    }
+    Ok(current_pos)
+}
+
+fn password(pos: &((usize, usize), Direction)) -> usize {
+    (pos.0 .0 + 1) * 4 + (pos.0 .1 + 1) * 1000 + get_direction_value(&pos.1)
+}
+
+pub fn part1(board: &Board) -> Result<usize, String> {
+    // 75388
+    Ok(password(&walk(board, None, None)?))
+}
+
+// one record of a traced walk, row and column are 1 based like in the password
+// face_id is None for a map that does not fold into a cube
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceStep {
+    pub row: usize,
+    pub col: usize,
+    pub facing: Direction,
+    pub face_id: Option<usize>,
+}
+
+// on_cube fails for a map that does not fold into a cube
+pub fn trace(board: &Board, on_cube: bool) -> Result<Vec<TraceStep>, String> {
+    let cube = fold_cube(board);
+    let walk_cube = match &cube {
+        Ok(cube) if on_cube => Some(cube),
+        Err(e) if on_cube => return Err(e.clone()),
+        _ => None,
+    };
+    let mut steps = vec![];
+    walk(board, walk_cube, Some(&mut steps))?;
+    Ok(steps
+        .into_iter()
+        .map(|(pos, facing)| TraceStep {
+            row: pos.1 + 1,
+            col: pos.0 + 1,
+            facing,
+            face_id: cube.as_ref().ok().and_then(|cube| get_cube_face_id_of_pos(&cube.faces, pos)),
+        })
+        .collect())
+}
+
+fn get_direction_char(direction: &Direction) -> char {
+    match direction {
+        Direction::Up => '^',
+        Direction::Down => 'v',
+        Direction::Left => '<',
+        Direction::Right => '>',
+    }
+}
+
+// the map with the last facing on every visited tile like in the puzzle text
+pub fn render_trace(board: &Board, trace: &[TraceStep]) -> String {
+    let mut breadcrumbs = HashMap::new();
+    for step in trace.iter() {
+        breadcrumbs.insert((step.col - 1, step.row - 1), get_direction_char(&step.facing));
+    }
+    let mut out = String::new();
+    for y in 0..board.max_y + 1 {
+        let line = board.grid_lines.get(&y).unwrap();
+        for x in 0..line.end + 1 {
+            let c = match (breadcrumbs.get(&(x, y)), board.grid.get(&(x, y))) {
+                (Some(c), _) => *c,
+                (None, Some(Tile::Wall)) => '#',
+                (None, Some(Tile::Space)) => '.',
+                (None, None) => ' ',
+            };
+            out.push(c);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+// one line per step, facing is the value used for the password
+// face_id stays empty without a cube
+pub fn trace_to_csv(trace: &[TraceStep]) -> String {
+    let mut csv = "row,col,facing,face_id\n".to_string();
+    for step in trace.iter() {
+        let face_id = step.face_id.map(|id| id.to_string()).unwrap_or_default();
+        csv += &format!("{},{},{},{}\n", step.row, step.col, get_direction_value(&step.facing), face_id);
+    }
+    csv
+}
+
+fn get_cube_face_id_of_pos(
+    cube_face_map: &HashMap<usize, CubeFace>,
+    pos: (usize, usize),
+) -> Option<usize> {
+    for cube_face in cube_face_map.iter() {
+        let face_id = cube_face.0;
+        let face = cube_face.1;
+
+        if face.x_range.contains(&pos.0) && face.y_range.contains(&pos.1) {
+            return Some(*face_id);
+        }
+    }
+    // should never happen
+    None
+}
+
+fn get_position_on_face_transition(
+    cube_face_map: &HashMap<usize, CubeFace>,
+    cube_face_edge_map: &HashMap<(usize, usize), Direction>,
+    edge: (usize, usize),
+    pos: (usize, usize),
+    direction: &Direction,
+) -> ((usize, usize), Direction) {
+    let current_face = cube_face_map.get(&edge.0).unwrap();
+    let next_face = cube_face_map.get(&edge.1).unwrap();
+    let next_face_start_x = next_face.x_range.start;
+    let next_face_end_x = next_face.x_range.end - 1;
+
+    let next_face_start_y = next_face.y_range.start;
+    let next_face_end_y = next_face.y_range.end - 1;
+
+    let pos_offset_x = pos.0 - current_face.x_range.start;
+    let pos_offset_y = pos.1 - current_face.y_range.start;
+
+    let new_direction = cube_face_edge_map.get(&edge).unwrap().clone();
+    match direction {
+        Direction::Up => match new_direction {
+            Direction::Up => (
+                (next_face_start_x + pos_offset_x, next_face_end_y),
+                new_direction,
+            ),
+            Direction::Down => (
+                (next_face_end_x - pos_offset_x, next_face_start_y),
+                new_direction,
+            ),
+            Direction::Left => {
+                let trans_pos = (next_face_end_x, next_face_end_y - pos_offset_x);
+                (trans_pos, new_direction)
+            }
+            Direction::Right => {
+                let trans_pos = (next_face_start_x, next_face_start_y + pos_offset_x);
+                (trans_pos, new_direction)
+            }
+        },
+        Direction::Down => match new_direction {
+            Direction::Up => (
+                (next_face_end_x - pos_offset_x, next_face_end_y),
+                new_direction,
+            ),
+
+            Direction::Down => (
+                (next_face_start_x + pos_offset_x, next_face_start_y),
+                new_direction,
+            ),
+            Direction::Left => {
+                let trans_pos = (next_face_end_x, next_face_start_y + pos_offset_x);
+                (trans_pos, new_direction)
+            }
+            Direction::Right => {
+                let trans_pos = (next_face_start_x, next_face_end_y - pos_offset_x);
+                (trans_pos, new_direction)
+            }
+        },
+        Direction::Left => match new_direction {
+            Direction::Up => {
+                let trans_pos = (next_face_end_x - pos_offset_y, next_face_end_y);
+                (trans_pos, new_direction)
+            }
+            Direction::Down => {
+                let trans_pos = (next_face_start_x + pos_offset_y, next_face_start_y);
+                (trans_pos, new_direction)
+            }
+            Direction::Left => (
+                (next_face_end_x, next_face_start_y + pos_offset_y),
+                new_direction,
+            ),
+            Direction::Right => (
+                (next_face_start_x, next_face_end_y - pos_offset_y),
+                new_direction,
+            ),
+        },
+        Direction::Right => match new_direction {
+            Direction::Up => {
+                let trans_pos = (next_face_start_x + pos_offset_y, next_face_end_y);
+                (trans_pos, new_direction)
+            }
+            Direction::Down => {
+                let trans_pos = (next_face_end_x - pos_offset_y, next_face_start_y);
+                (trans_pos, new_direction)
+            }
+            Direction::Left => (
+                (next_face_end_x, next_face_end_y - pos_offset_y),
+                new_direction,
+            ),
+            Direction::Right => (
+                (next_face_start_x, next_face_start_y + pos_offset_y),
+                new_direction,
+            ),
+        },
+    }
+}
+
+fn get_possible_position_on_face(
+    grid: &HashMap<(usize, usize), Tile>,
+    cube_face_map: &HashMap<usize, CubeFace>,
+    cube_face_edge_map: &HashMap<(usize, usize), Direction>,
+    steps: usize,
+    pos: (usize, usize),
+    direction: &Direction,
+    mut trace: Option<&mut Vec<((usize, usize), Direction)>>,
+) -> Result<((usize, usize), Direction), String> {
+    let mut move_pos = (pos, direction.clone());
+    for _ in 0..steps {
+        let old_pos = move_pos.clone();
+
+        let current_face_id = get_cube_face_id_of_pos(cube_face_map, move_pos.0).unwrap();
+        let current_face = cube_face_map.get(&current_face_id).unwrap();
+
+        let move_pos_pos = move_pos.0;
+        let move_pos_direction = &move_pos.1;
+
+        match move_pos_direction {
+            Direction::Up => {
+                if move_pos_pos.1 == current_face.y_range.start {
+                    let edge = (current_face_id, current_face.up_face_id);
+                    move_pos = get_position_on_face_transition(
+                        cube_face_map,
+                        cube_face_edge_map,
+                        edge,
+                        move_pos_pos,
+                        move_pos_direction,
+                    );
+                } else {
+                    move_pos = (
+                        (move_pos_pos.0, move_pos_pos.1 - 1),
+                        move_pos_direction.clone(),
+                    )
+                }
+                if tile_at(grid, move_pos.0)? == &Tile::Wall {
+                    return Ok(old_pos);
+                }
+                if let Some(trace) = trace.as_mut() {
+                    trace.push(move_pos.clone());
+                }
+            }
+            Direction::Down => {
+                if move_pos_pos.1 == current_face.y_range.end - 1 {
+                    let edge = (current_face_id, current_face.down_face_id);
+                    move_pos = get_position_on_face_transition(
+                        cube_face_map,
+                        cube_face_edge_map,
+                        edge,
+                        move_pos_pos,
+                        move_pos_direction,
+                    );
+                } else {
+                    move_pos = (
+                        (move_pos_pos.0, move_pos_pos.1 + 1),
+                        move_pos_direction.clone(),
+                    )
+                }
+                if tile_at(grid, move_pos.0)? == &Tile::Wall {
+                    return Ok(old_pos);
+                }
+                if let Some(trace) = trace.as_mut() {
+                    trace.push(move_pos.clone());
+                }
+            }
+            Direction::Left => {
+                if move_pos_pos.0 == current_face.x_range.start {
+                    let edge = (current_face_id, current_face.left_face_id);
+                    move_pos = get_position_on_face_transition(
+                        cube_face_map,
+                        cube_face_edge_map,
+                        edge,
+                        move_pos_pos,
+                        move_pos_direction,
+                    );
+                } else {
+                    move_pos = (
+                        (move_pos_pos.0 - 1, move_pos_pos.1),
+                        move_pos_direction.clone(),
+                    )
+                }
+                if tile_at(grid, move_pos.0)? == &Tile::Wall {
+                    return Ok(old_pos);
+                }
+                if let Some(trace) = trace.as_mut() {
+                    trace.push(move_pos.clone());
+                }
+            }
+            Direction::Right => {
+                if move_pos_pos.0 == current_face.x_range.end - 1 {
+                    let edge = (current_face_id, current_face.right_face_id);
+                    move_pos = get_position_on_face_transition(
+                        cube_face_map,
+                        cube_face_edge_map,
+                        edge,
+                        move_pos_pos,
+                        move_pos_direction,
+                    );
+                } else {
+                    move_pos = (
+                        (move_pos_pos.0 + 1, move_pos_pos.1),
+                        move_pos_direction.clone(),
+                    )
+                }
+                if tile_at(grid, move_pos.0)? == &Tile::Wall {
+                    return Ok(old_pos);
+                }
+                if let Some(trace) = trace.as_mut() {
+                    trace.push(move_pos.clone());
+                }
+            }
+        }
+    }
+    Ok(move_pos)
+}
+
+// a direction on the cube as unit vector
+type Vec3 = (i32, i32, i32);
+
+fn neg(v: Vec3) -> Vec3 {
+    (-v.0, -v.1, -v.2)
+}
+
+// where a face of the net ends up on the cube: the outside normal and the
+// directions right and down of the net point to
+#[derive(Debug, Clone, Copy)]
+struct FaceOrientation {
+    normal: Vec3,
+    right: Vec3,
+    down: Vec3,
+}
+
+impl FaceOrientation {
+    fn to_vec3(self, direction: &Direction) -> Vec3 {
+        match direction {
+            Direction::Right => self.right,
+            Direction::Left => neg(self.right),
+            Direction::Down => self.down,
+            Direction::Up => neg(self.down),
+        }
+    }
+
+    fn to_direction(self, v: Vec3) -> Option<Direction> {
+        [Direction::Right, Direction::Left, Direction::Down, Direction::Up]
+            .into_iter()
+            .find(|d| self.to_vec3(d) == v)
+    }
+
+    // the neighbour face of the net after folding it over the edge
+    fn fold(self, direction: &Direction) -> FaceOrientation {
+        let n = self.normal;
+        match direction {
+            Direction::Right => FaceOrientation { normal: self.right, right: neg(n), down: self.down },
+            Direction::Left => FaceOrientation { normal: neg(self.right), right: n, down: self.down },
+            Direction::Down => FaceOrientation { normal: self.down, right: self.right, down: neg(n) },
+            Direction::Up => FaceOrientation { normal: neg(self.down), right: self.right, down: n },
+        }
+    }
+}
+
+// fold the map into a cube - works for every net and edge length
+// faces are numbered in reading order of the net
+pub fn fold_cube(board: &Board) -> Result<Cube, String> {
+    let (grid, max_x, max_y) = (&board.grid, board.max_x, board.max_y);
+    let edge_length = (1..).find(|n| n * n * 6 >= grid.len()).unwrap();
+    if edge_length * edge_length * 6 != grid.len() {
+        return Err(format!("{} tiles can not be folded into a cube", grid.len()));
+    }
+
+    // the faces as (x, y) of the net
+    let mut net: Vec<(usize, usize)> = vec![];
+    for y in 0..(max_y + 1) / edge_length {
+        for x in 0..(max_x + 1) / edge_length {
+            if grid.contains_key(&(x * edge_length, y * edge_length)) {
+                net.push((x, y));
+            }
+        }
+    }
+    let all_on_faces = grid.keys().all(|(x, y)| net.contains(&(x / edge_length, y / edge_length)));
+    if net.len() != 6 || !all_on_faces {
+        return Err(format!("the map is no cube net with an edge length of {}", edge_length));
+    }
+
+    // walk over the net and fold every neighbour of a face
+    let mut orientations: HashMap<usize, FaceOrientation> = HashMap::new();
+    orientations.insert(0, FaceOrientation { normal: (0, 0, 1), right: (1, 0, 0), down: (0, 1, 0) });
+    let mut todo = vec![0];
+    while let Some(face_id) = todo.pop() {
+        let (x, y) = net[face_id];
+        let orientation = orientations[&face_id];
+        let neighbours = [
+            (Direction::Right, Some((x + 1, y))),
+            (Direction::Left, x.checked_sub(1).map(|x| (x, y))),
+            (Direction::Down, Some((x, y + 1))),
+            (Direction::Up, y.checked_sub(1).map(|y| (x, y))),
+        ];
+        for (direction, pos) in neighbours {
+            let Some(next_id) = pos.and_then(|pos| net.iter().position(|p| *p == pos)) else {
+                continue;
+            };
+            if let Entry::Vacant(e) = orientations.entry(next_id) {
+                e.insert(orientation.fold(&direction));
+                todo.push(next_id);
+            }
+        }
+    }
+    let mut normals = orientations.values().map(|o| o.normal).collect::<Vec<_>>();
+    normals.sort();
+    normals.dedup();
+    if orientations.len() != 6 || normals.len() != 6 {
+        return Err("the net does not fold into a cube".to_string());
+    }
+
+    let mut cube_face_map: HashMap<usize, CubeFace> = HashMap::new();
+    let mut cube_face_edge_map: HashMap<(usize, usize), Direction> = HashMap::new();
+    for (face_id, &(x, y)) in net.iter().enumerate() {
+        let orientation = orientations[&face_id];
+        let mut neighbour_ids = vec![];
+        for direction in [Direction::Down, Direction::Up, Direction::Left, Direction::Right] {
+            // leaving in direction we arrive at the face with this normal
+            let leave = orientation.to_vec3(&direction);
+            let (next_id, next) = orientations.iter().find(|(_, o)| o.normal == leave).unwrap();
+            // and go down the side of the cube there
+            let enter = next.to_direction(neg(orientation.normal)).unwrap();
+            cube_face_edge_map.insert((face_id, *next_id), enter);
+            neighbour_ids.push(*next_id);
+        }
+        let cube_face = CubeFace {
+            x_range: x * edge_length..(x + 1) * edge_length,
+            y_range: y * edge_length..(y + 1) * edge_length,
+            down_face_id: neighbour_ids[0],
+            up_face_id: neighbour_ids[1],
+            left_face_id: neighbour_ids[2],
+            right_face_id: neighbour_ids[3],
+        };
+        cube_face_map.insert(face_id, cube_face);
+    }
+    Ok(Cube {
+        faces: cube_face_map,
+        edges: cube_face_edge_map,
+    })
+}
+
+// fails for a map that does not fold into a cube
+pub fn part2(board: &Board) -> Result<usize, String> {
+    //  182170
+    let cube = fold_cube(board)?;
+    Ok(password(&walk(board, Some(&cube), None)?))
+}
+
+pub struct Day22;
+
+impl Solver for Day22 {
+    type Input = Board;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, Error> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, Error> {
+        part1(input).map(|password| password.to_string()).map_err(|e| Error::input(DAY, &e))
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, Error> {
+        part2(input).map(|password| password.to_string()).map_err(|e| Error::input(DAY, &e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fold_cube, get_possible_position_on_face, parse, part1, part2, render_trace, trace, trace_to_csv, Direction};
+
+    #[test]
+    fn it_works() {
+        let lines = vec![
+            "        ...#",
+            "        .#..",
+            "        #...",
+            "        ....",
+            "...#.......#",
+            "........#...",
+            "..#....#....",
+            "..........#.",
+            "        ...#....",
+            "        .....#..",
+            "        .#......",
+            "        ......#.",
+            "",
+            "10R5L5R10L4R5L5",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect::<Vec<_>>();
+
+        let input = parse(&lines).unwrap();
+
+        let result = part1(&input);
+        assert_eq!(result, Ok(6032));
+        let result = part2(&input);
+        assert_eq!(result, Ok(5031));
+
+        let steps = trace(&input, true).unwrap();
+        let last = steps.last().unwrap();
+        assert_eq!((last.row, last.col, last.face_id), (5, 7, Some(2)));
+        assert_eq!(render_trace(&input, &steps).lines().next().unwrap(), "        >>v#");
+        assert_eq!(trace_to_csv(&steps).lines().nth(1).unwrap(), "1,9,0,0");
+
+        // six faces in a row do not fold, but part 1 only needs the flat map
+        let lines = ["......", "", "1"].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let input = parse(&lines).unwrap();
+        assert_eq!(part1(&input), Ok(1008));
+        assert_eq!(part2(&input), Err("the net does not fold into a cube".to_string()));
+        assert_eq!(trace(&input, false).unwrap()[1].face_id, None);
+        assert!(trace(&input, true).is_err());
+
+        // the layout of the real input with an edge length of 50
+        //  AB
+        //  C
+        // DE
+        // F
+        let mut lines = vec![];
+        for y in 0..200 {
+            let line = match y / 50 {
+                0 => format!("{}{}", " ".repeat(50), ".".repeat(100)),
+                1 => format!("{}{}", " ".repeat(50), ".".repeat(50)),
+                2 => ".".repeat(100),
+                _ => ".".repeat(50),
+            };
+            lines.push(line);
+        }
+        lines.push("".to_string());
+        lines.push("1".to_string());
+        let input = parse(&lines).unwrap();
+        let cube = fold_cube(&input).unwrap();
+        let step = |pos: (usize, usize), direction: Direction| {
+            get_possible_position_on_face(&input.grid, &cube.faces, &cube.edges, 1, pos, &direction, None).unwrap()
+        };
+        // A up to the left of F, B up to the bottom of F
+        assert_eq!(step((57, 0), Direction::Up), ((0, 157), Direction::Right));
+        assert_eq!(step((107, 0), Direction::Up), ((7, 199), Direction::Up));
+        assert_eq!(step((7, 199), Direction::Down), ((107, 0), Direction::Down));
+        // B right to E upside down, B down to the right of C
+        assert_eq!(step((149, 7), Direction::Right), ((99, 142), Direction::Left));
+        assert_eq!(step((107, 49), Direction::Down), ((99, 57), Direction::Left));
+        // A left to D upside down, C left to the top of D
+        assert_eq!(step((50, 7), Direction::Left), ((0, 142), Direction::Right));
+        assert_eq!(step((50, 57), Direction::Left), ((7, 100), Direction::Down));
+        // E down to the right of F and back
+        assert_eq!(step((57, 149), Direction::Down), ((49, 157), Direction::Left));
+        assert_eq!(step((49, 157), Direction::Right), ((57, 149), Direction::Up));
+        assert_eq!(part2(&input), Ok(1000 + 4 * 52));
+
+        let to_lines = |lines: &[&str]| lines.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let e = parse(&to_lines(&["", "1"])).unwrap_err();
+        assert_eq!(e.to_string(), "day 22 line 1: the map is empty");
+        let e = parse(&to_lines(&[". .", "", "1"])).unwrap_err();
+        assert_eq!(e.to_string(), "day 22 line 1: gap between the tiles");
+        // wraps around at the last tile and not at the trailing spaces
+        let input = parse(&to_lines(&["....  ", "", "6"])).unwrap();
+        assert_eq!(part1(&input), Ok(1000 + 4 * 3));
+        // the second column has a hole, so going down from the last line leaves the map
+        let input = parse(&to_lines(&["..", ".", "..", "", "R2L1R1"])).unwrap();
+        assert_eq!(part1(&input), Err("row 4 column 2 is not on the map".to_string()));
+    }
+}
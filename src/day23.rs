@@ -0,0 +1,239 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{Display, Formatter};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+use crate::solver::Solver;
+use crate::error::Error;
+use crate::grid::Grid;
+
+const DAY: usize = 23;
+
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter)]
+enum Direction {
+    North,
+    NorthWest,
+    NorthEast,
+    South,
+    SouthWest,
+    SouthEast,
+    West,
+    East,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Tile {
+    Elve,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tile::Elve => { write!(f, "#") }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elve {
+    pub id: usize,
+    pub pos: (i32, i32),
+}
+
+impl Elve {
+    fn get_positions(&self) -> Vec<(i32, i32)> {
+        let mut pos_list = vec![];
+        for d in Direction::iter() {
+            pos_list.push(get_positions_at_direction(self.pos, &d));
+        }
+        pos_list
+    }
+
+    fn move_elve(&mut self, move_pos: (i32, i32)) {
+        self.pos = move_pos;
+    }
+    fn move_elve_wish(
+        &self,
+        grid: &Grid<Tile>,
+        directions: &VecDeque<(Direction, Vec<Direction>)>,
+    ) -> (i32, i32) {
+        let all_free = self
+            .get_positions()
+            .iter()
+            .filter(|pos| !grid.contains(**pos))
+            .count()
+            == 8;
+        if !all_free {
+            for d in directions.iter() {
+                let move_possible =
+                    d.1.iter()
+                        .filter(|direction| {
+                            !grid.contains(get_positions_at_direction(self.pos, direction))
+                        })
+                        .count()
+                        == 3;
+                if move_possible {
+                    return get_positions_at_direction(self.pos, &d.0);
+                }
+            }
+        }
+        self.pos
+    }
+}
+
+fn get_positions_at_direction(pos: (i32, i32), direction: &Direction) -> (i32, i32) {
+    match direction {
+        Direction::North => (pos.0, pos.1 - 1),
+        Direction::NorthWest => (pos.0 - 1, pos.1 - 1),
+        Direction::NorthEast => (pos.0 + 1, pos.1 - 1),
+        Direction::South => (pos.0, pos.1 + 1),
+        Direction::SouthWest => (pos.0 - 1, pos.1 + 1),
+        Direction::SouthEast => (pos.0 + 1, pos.1 + 1),
+        Direction::West => (pos.0 - 1, pos.1),
+        Direction::East => (pos.0 + 1, pos.1),
+    }
+}
+
+fn get_elves_area_space_tiles(grid: &Grid<Tile>) -> usize {
+    grid.width() * grid.height() - grid.len()
+}
+
+#[allow(dead_code)]
+fn print_grid(grid: &Grid<Tile>) {
+    println!("{:?}", grid.bounds());
+    print!("{}", grid);
+}
+
+fn update_grid(elves_map: &HashMap<usize, Elve>, grid: &mut Grid<Tile>) {
+    grid.clear();
+    for elve in elves_map.values() {
+        grid.set(elve.pos, Tile::Elve);
+    }
+}
+
+pub fn parse(lines: &[String]) -> Result<Vec<Elve>, Error> {
+    let mut elves = vec![];
+    let mut elve_id = 0;
+    for (y, line) in lines.iter().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            let pos = (x as i32, y as i32);
+            if c == '#' {
+                elves.push(Elve { id: elve_id, pos });
+                elve_id += 1;
+            } else if c != '.' {
+                return Err(Error::unexpected_char(DAY, y, x, c));
+            }
+        }
+    }
+    Ok(elves)
+}
+
+fn part12(elves: &[Elve], part2: bool) -> usize {
+    // part1 = 3871
+    // part2 = 925
+    let mut grid: Grid<Tile> = Grid::sparse();
+    let mut elves_map: HashMap<usize, Elve> = HashMap::new();
+    for e in elves.iter() {
+        elves_map.insert(e.id, e.clone());
+    }
+    update_grid(&elves_map, &mut grid);
+
+    let mut wish_direction_list: VecDeque<(Direction, Vec<Direction>)> = VecDeque::new();
+    wish_direction_list.push_back((
+        Direction::North,
+        vec![Direction::North, Direction::NorthEast, Direction::NorthWest],
+    ));
+    wish_direction_list.push_back((
+        Direction::South,
+        vec![Direction::South, Direction::SouthEast, Direction::SouthWest],
+    ));
+    wish_direction_list.push_back((
+        Direction::West,
+        vec![Direction::West, Direction::NorthWest, Direction::SouthWest],
+    ));
+    wish_direction_list.push_back((
+        Direction::East,
+        vec![Direction::East, Direction::NorthEast, Direction::SouthEast],
+    ));
+    // println!("{:?}", wish_direction_list);
+
+    let rounds = if part2 { 1000 } else { 10 };
+    for round in 0..rounds {
+        // print_grid(&grid);
+        let mut wishes: HashMap<(i32, i32), Vec<usize>> = HashMap::new();
+        for e in elves_map.values() {
+            let move_pos = e.move_elve_wish(&grid, &wish_direction_list);
+            if move_pos != e.pos {
+                wishes
+                    .entry(move_pos)
+                    .and_modify(|list| list.push(e.id))
+                    .or_insert(vec![e.id]);
+            }
+        }
+
+        let moves = wishes
+            .iter()
+            .filter(|entry| entry.1.len() == 1)
+            .collect::<Vec<_>>();
+
+        if part2 && moves.is_empty() {
+            return round + 1;
+        }
+        for e in moves.iter() {
+            let move_pos = e.0;
+            let move_elve_id = e.1.first().unwrap();
+            let elve = elves_map.get_mut(move_elve_id).unwrap();
+            elve.move_elve(*move_pos);
+        }
+        wish_direction_list.rotate_left(1);
+        update_grid(&elves_map, &mut grid);
+    }
+    get_elves_area_space_tiles(&grid)
+}
+
+pub fn part1(elves: &[Elve]) -> usize {
+    // 3871
+    part12(elves, false)
+}
+
+pub fn part2(elves: &[Elve]) -> usize {
+    // 925
+    part12(elves, true)
+}
+
+pub struct Day23;
+
+impl Solver for Day23 {
+    type Input = Vec<Elve>;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, Error> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, Error> {
+        Ok(part1(input).to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, Error> {
+        Ok(part2(input).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+
+    #[test]
+    fn it_works() {
+        let lines = ["....#..", "..###.#", "#...#.#", ".#...##", "#.###..", "##.#.##", ".#..#.."]
+        .iter()
+        .map(|s| s.to_string())
+        .collect::<Vec<_>>();
+
+        let input = parse(&lines).unwrap();
+
+        let result = part1(&input);
+        assert_eq!(result, 110);
+        let result = part2(&input);
+        assert_eq!(result, 20);
+    }
+}
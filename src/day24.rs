@@ -0,0 +1,434 @@
+use std::cmp::PartialEq;
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+use crate::solver::Solver;
+use crate::error::Error;
+use crate::grid::Grid as TileGrid;
+use crate::search::astar;
+
+const DAY: usize = 24;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Tile {
+    Wall,
+    Ground,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter)]
+pub enum BlizzardDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for BlizzardDirection {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' => Ok(BlizzardDirection::Up),
+            'v' => Ok(BlizzardDirection::Down),
+            '>' => Ok(BlizzardDirection::Right),
+            '<' => Ok(BlizzardDirection::Left),
+            _ => Err(c),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, Hash)]
+enum Step {
+    Up,
+    Down,
+    Left,
+    Right,
+    Wait,
+}
+
+fn get_possible_steps(grid: &Grid, pos: (u16, u16), time: u32) -> Vec<Step> {
+    let mut possible_steps: Vec<Step> = vec![];
+    // IMPORTANT!!! we can only wait if current spot stays free
+    if grid.is_free(pos, time) {
+        possible_steps.push(Step::Wait);
+    }
+
+    for d in Step::iter() {
+        if d == Step::Wait {
+            continue;
+        }
+        // nothing is free outside of the valley
+        let Some(pos) = get_step_position(pos, &d) else {
+            continue;
+        };
+        if !grid.is_free(pos, time) {
+            continue;
+        }
+        possible_steps.push(d)
+    }
+
+    possible_steps
+}
+
+// None where a step would leave the grid through the top or the left
+fn get_step_position(pos: (u16, u16), step: &Step) -> Option<(u16, u16)> {
+    match step {
+        Step::Up => Some((pos.0, pos.1.checked_sub(1)?)),
+        Step::Down => Some((pos.0, pos.1 + 1)),
+        Step::Left => Some((pos.0.checked_sub(1)?, pos.1)),
+        Step::Right => Some((pos.0 + 1, pos.1)),
+        Step::Wait => Some(pos),
+    }
+}
+
+// one bit per cell of a row or a column of the valley
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Bits {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl Bits {
+    fn new(len: usize) -> Bits {
+        Bits {
+            words: vec![0; len.div_ceil(64)],
+            len,
+        }
+    }
+
+    fn set(&mut self, idx: usize) {
+        self.words[idx / 64] |= 1 << (idx % 64);
+    }
+
+    fn get(&self, idx: usize) -> bool {
+        self.words[idx / 64] & (1 << (idx % 64)) != 0
+    }
+
+    // bit idx after all bits were rotated by shift towards higher indices
+    fn get_rotated(&self, idx: usize, shift: usize) -> bool {
+        self.get((idx + self.len - shift % self.len) % self.len)
+    }
+}
+
+// the blizzards where they are at minute 0, x and y start at 0 inside the walls
+// a blizzard stays in its row or column and wraps around,
+// so minute t is the bits of minute 0 rotated by t
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Blizzards {
+    width: usize,
+    height: usize,
+    // one per row
+    right: Vec<Bits>,
+    left: Vec<Bits>,
+    // one per column
+    down: Vec<Bits>,
+    up: Vec<Bits>,
+}
+
+impl Blizzards {
+    fn new(width: usize, height: usize) -> Blizzards {
+        Blizzards {
+            width,
+            height,
+            right: vec![Bits::new(width); height],
+            left: vec![Bits::new(width); height],
+            down: vec![Bits::new(height); width],
+            up: vec![Bits::new(height); width],
+        }
+    }
+
+    fn add(&mut self, pos: (usize, usize), direction: &BlizzardDirection) {
+        match direction {
+            BlizzardDirection::Up => self.up[pos.0].set(pos.1),
+            BlizzardDirection::Down => self.down[pos.0].set(pos.1),
+            BlizzardDirection::Left => self.left[pos.1].set(pos.0),
+            BlizzardDirection::Right => self.right[pos.1].set(pos.0),
+        }
+    }
+
+    // a blizzard going into direction is at pos at minute time
+    fn has(&self, pos: (usize, usize), time: u32, direction: &BlizzardDirection) -> bool {
+        let t = time as usize;
+        match direction {
+            BlizzardDirection::Up => self.up[pos.0].get_rotated(pos.1, self.height - t % self.height),
+            BlizzardDirection::Down => self.down[pos.0].get_rotated(pos.1, t),
+            BlizzardDirection::Left => self.left[pos.1].get_rotated(pos.0, self.width - t % self.width),
+            BlizzardDirection::Right => self.right[pos.1].get_rotated(pos.0, t),
+        }
+    }
+
+    // the blizzards that are at pos at minute time
+    fn at(&self, pos: (usize, usize), time: u32) -> Vec<BlizzardDirection> {
+        BlizzardDirection::iter().filter(|direction| self.has(pos, time, direction)).collect()
+    }
+
+    // called for every step of the search, so no Vec here
+    fn is_occupied(&self, pos: (usize, usize), time: u32) -> bool {
+        self.has(pos, time, &BlizzardDirection::Right)
+            || self.has(pos, time, &BlizzardDirection::Left)
+            || self.has(pos, time, &BlizzardDirection::Down)
+            || self.has(pos, time, &BlizzardDirection::Up)
+    }
+
+    // minutes until every blizzard is back where it started
+    fn period(&self) -> u32 {
+        lcm(self.width as u32, self.height as u32)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Grid {
+    pub grid_data: TileGrid<Tile>,
+    pub grid_width: u16,
+    pub grid_height: u16,
+    pub blizzards: Blizzards,
+    pub start_pos: (u16, u16),
+    pub end_pos: (u16, u16),
+}
+
+impl Grid {
+    // the expedition can be at pos at minute time
+    fn is_free(&self, pos: (u16, u16), time: u32) -> bool {
+        // start and end must always be free
+        if pos == self.start_pos || pos == self.end_pos {
+            return true;
+        }
+        if !self.in_valley(pos) {
+            return false;
+        }
+        !self.blizzards.is_occupied((pos.0 as usize - 1, pos.1 as usize - 1), time)
+    }
+
+    // the two gaps and everything inside the walls
+    pub fn in_valley(&self, pos: (u16, u16)) -> bool {
+        let inside = pos.0 > 0 && pos.1 > 0 && pos.0 < self.grid_width - 1 && pos.1 < self.grid_height - 1;
+        inside || pos == self.start_pos || pos == self.end_pos
+    }
+}
+
+#[allow(dead_code)]
+fn print_grid(grid: &Grid, expedition: (u16, u16), time: u32) {
+    for y in 0..grid.grid_height {
+        for x in 0..grid.grid_width {
+            let pos = (x, y);
+            let inside = x > 0 && y > 0 && x < grid.grid_width - 1 && y < grid.grid_height - 1;
+            let blizzards = if inside {
+                grid.blizzards.at((x as usize - 1, y as usize - 1), time)
+            } else {
+                vec![]
+            };
+
+            if blizzards.len() > 1 {
+                print!("{}", blizzards.len())
+            } else if let Some(blizzard) = blizzards.first() {
+                match blizzard {
+                    BlizzardDirection::Up => print!("^"),
+                    BlizzardDirection::Down => print!("v"),
+                    BlizzardDirection::Left => print!("<"),
+                    BlizzardDirection::Right => print!(">"),
+                }
+            } else if expedition == pos {
+                print!("E")
+            } else {
+                match grid.grid_data.get((x as i32, y as i32)).unwrap() {
+                    Tile::Ground => {
+                        print!(".")
+                    }
+                    Tile::Wall => {
+                        print!("#")
+                    }
+                }
+            }
+        }
+        println!();
+    }
+}
+
+// the blizzards are the same every lcm minutes so a minute only counts modulo that
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct State {
+    pos: (u16, u16),
+    phase: u32,
+}
+
+fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+fn lcm(a: u32, b: u32) -> u32 {
+    a / gcd(a, b) * b
+}
+
+// the minutes from start to end when leaving start at start_time
+fn find_way(grid: &Grid, start: (u16, u16), end: (u16, u16), start_time: u32) -> Option<u32> {
+    let period = grid.blizzards.period();
+    let start = State {
+        pos: start,
+        phase: start_time % period,
+    };
+    let successors = |current: &State| {
+        let next_phase = (current.phase + 1) % period;
+        get_possible_steps(grid, current.pos, next_phase)
+            .iter()
+            .filter_map(|step| get_step_position(current.pos, step))
+            .map(|pos| (State { pos, phase: next_phase }, 1))
+            .collect::<Vec<_>>()
+    };
+    let manhattan = |current: &State| (current.pos.0.abs_diff(end.0) + current.pos.1.abs_diff(end.1)) as u32;
+    astar(start, successors, manhattan, |current| current.pos == end).map(|path| path.cost)
+}
+
+pub fn parse(lines: &[String]) -> Result<Grid, Error> {
+    let mut blizzard_list = vec![];
+    let grid_data = TileGrid::parse(DAY, lines, |pos, c| {
+        match c {
+            '#' => Some(Tile::Wall),
+            '.' => Some(Tile::Ground),
+            _ => {
+                blizzard_list.push((pos, BlizzardDirection::try_from(c).ok()?));
+                Some(Tile::Ground)
+            }
+        }
+    })?;
+    if grid_data.height() < 3 || grid_data.width() < 3 {
+        return Err(Error::input(DAY, "the valley needs at least 3 lines and 3 columns"));
+    }
+
+    let (width, height) = (grid_data.width() - 2, grid_data.height() - 2);
+    let mut blizzards = Blizzards::new(width, height);
+    for (pos, direction) in blizzard_list.iter() {
+        let (x, y) = (pos.0 as usize, pos.1 as usize);
+        if x == 0 || y == 0 || x > width || y > height {
+            // the line of the blizzard, empty lines are skipped by the grid
+            let line_idx = lines.iter().enumerate().filter(|(_, line)| !line.is_empty()).nth(y).unwrap().0;
+            return Err(Error::parse(DAY, line_idx, &format!("blizzard at column {} is inside the wall", x + 1)));
+        }
+        blizzards.add((x - 1, y - 1), direction);
+    }
+
+    // the gaps in the top and bottom wall
+    let last_y = grid_data.height() as i32 - 1;
+    let start_pos = grid_data.row(0).find(|(_, tile)| **tile == Tile::Ground);
+    let end_pos = grid_data.row(last_y).find(|(_, tile)| **tile == Tile::Ground);
+    let (Some((start_pos, _)), Some((end_pos, _))) = (start_pos, end_pos) else {
+        return Err(Error::input(DAY, "no gap in the top or bottom wall"));
+    };
+
+    Ok(Grid {
+        grid_width: grid_data.width() as u16,
+        grid_height: grid_data.height() as u16,
+        grid_data,
+        blizzards,
+        start_pos: (start_pos.0 as u16, start_pos.1 as u16),
+        end_pos: (end_pos.0 as u16, end_pos.1 as u16),
+    })
+}
+
+// the minutes of every leg when the expedition goes from waypoint to waypoint
+// and is at the first one at minute 0
+pub fn plan_trip(grid: &Grid, waypoints: &[(u16, u16)]) -> Result<Vec<u32>, String> {
+    if let Some(pos) = waypoints.iter().find(|pos| !grid.in_valley(**pos)) {
+        return Err(format!("waypoint {},{} is not in the valley", pos.0, pos.1));
+    }
+    if let Some(pos) = waypoints.first().filter(|pos| !grid.is_free(**pos, 0)) {
+        return Err(format!("waypoint {},{} is in a blizzard at minute 0", pos.0, pos.1));
+    }
+
+    let mut time = 0;
+    let mut legs = vec![];
+    for leg in waypoints.windows(2) {
+        let minutes = find_way(grid, leg[0], leg[1], time)
+            .ok_or_else(|| format!("there is no way from {},{} to {},{}", leg[0].0, leg[0].1, leg[1].0, leg[1].1))?;
+        time += minutes;
+        legs.push(minutes);
+    }
+    Ok(legs)
+}
+
+fn trip_time(grid: &Grid, waypoints: &[(u16, u16)]) -> Result<u32, Error> {
+    plan_trip(grid, waypoints).map(|legs| legs.iter().sum()).map_err(|e| Error::input(DAY, &e))
+}
+
+pub fn part1(grid: &Grid) -> Result<u32, Error> {
+    // 253
+    trip_time(grid, &[grid.start_pos, grid.end_pos])
+}
+
+pub fn part2(grid: &Grid) -> Result<u32, Error> {
+    // 794
+    // back to the start for the snacks
+    trip_time(grid, &[grid.start_pos, grid.end_pos, grid.start_pos, grid.end_pos])
+}
+
+pub struct Day24;
+
+impl Solver for Day24 {
+    type Input = Grid;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, Error> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, Error> {
+        part1(input).map(|minutes| minutes.to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, Error> {
+        part2(input).map(|minutes| minutes.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2, plan_trip, BlizzardDirection};
+
+    #[test]
+    fn it_works() {
+        let lines = ["#.######", "#>>.<^<#", "#.<..<<#", "#>v.><>#", "#<^v^^>#", "######.#"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect::<Vec<_>>();
+
+        let input = parse(&lines).unwrap();
+
+        let result = part1(&input);
+        assert_eq!(result, Ok(18));
+        let result = part2(&input);
+        assert_eq!(result, Ok(54));
+
+        let trip = [input.start_pos, input.end_pos, input.start_pos, input.end_pos];
+        assert_eq!(plan_trip(&input, &trip), Ok(vec![18, 23, 13]));
+        // a detour to the lower left corner
+        assert_eq!(plan_trip(&input, &[input.start_pos, (1, 4), input.end_pos]), Ok(vec![16, 13]));
+        assert_eq!(plan_trip(&input, &[input.start_pos, (0, 0)]), Err("waypoint 0,0 is not in the valley".to_string()));
+        assert_eq!(plan_trip(&input, &[(7, 1), input.end_pos]), Err("waypoint 7,1 is not in the valley".to_string()));
+        assert_eq!(plan_trip(&input, &[(0, 1), input.end_pos]), Err("waypoint 0,1 is not in the valley".to_string()));
+        assert_eq!(plan_trip(&input, &[(1, 1), input.end_pos]), Err("waypoint 1,1 is in a blizzard at minute 0".to_string()));
+
+        // minute 1 of the example, x and y inside the walls
+        assert_eq!(input.blizzards.at((2, 0), 1).len(), 3);
+        assert_eq!(input.blizzards.at((0, 1), 1), vec![BlizzardDirection::Left]);
+        assert!(!input.blizzards.is_occupied((0, 0), 1));
+        assert_eq!(input.blizzards.at((2, 0), 13), input.blizzards.at((2, 0), 1));
+
+        // a basin far too big to keep every minute of
+        let mut lines = vec![format!("#.{}", "#".repeat(498))];
+        for y in 0..298 {
+            let blizzard = if y % 2 == 0 { ">" } else { "<" };
+            lines.push(format!("#{}{}#", blizzard, ".".repeat(497)));
+        }
+        lines.push(format!("{}.#", "#".repeat(498)));
+        let input = parse(&lines).unwrap();
+        assert_eq!(input.blizzards.period(), 498 * 298 / 2);
+        // the blizzards never get in the way
+        assert_eq!(part1(&input), Ok(497 + 299));
+
+        // the only tile of the valley always has a blizzard on it
+        let lines = ["#.#", "#^#", "#.#"].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let input = parse(&lines).unwrap();
+        assert_eq!(part1(&input).unwrap_err().to_string(), "day 24: there is no way from 1,0 to 1,2");
+
+        let lines = ["#>######", "#.....##", "######.#"].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let e = parse(&lines).unwrap_err();
+        assert_eq!(e.to_string(), "day 24 line 1: blizzard at column 2 is inside the wall");
+    }
+}
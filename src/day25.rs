@@ -0,0 +1,175 @@
+use std::collections::VecDeque;
+use crate::solver::Solver;
+use crate::error::Error;
+
+const DAY: usize = 25;
+
+fn snafu_to_digit(c: char) -> Option<i32> {
+    match c {
+        '2' => Some(2),
+        '1' => Some(1),
+        '0' => Some(0),
+        '-' => Some(-1),
+        '=' => Some(-2),
+        _ => None,
+    }
+}
+
+fn max_digit_for_mul(mul: i32) -> i64 {
+    let mut sum = 2 * 5_i64.pow(mul.try_into().unwrap());
+    for i in 0..mul {
+        sum += 2 * 5_i64.pow(i.try_into().unwrap());
+    }
+    sum
+}
+fn min_digit_for_mul(mul: i32) -> i64 {
+    let mut sum = 5_i64.pow(mul.try_into().unwrap());
+    for i in 0..mul {
+        sum -= 2 * 5_i64.pow(i.try_into().unwrap());
+    }
+    sum
+}
+
+fn try_resolve(sum: i64, rem: i64, steps: usize, operations: &mut VecDeque<i64>) -> bool {
+    if steps == 0 {
+        if sum == rem {
+            return true;
+        }
+
+        return false;
+    }
+    let f = 5_i64.pow((steps - 1).try_into().unwrap());
+
+    for i in [2, 1, 0, -1, -2].iter() {
+        // println!("{:?}", operations);
+        let rem = rem + i * f;
+        let max = max_digit_for_mul((steps - 1) as i32);
+        // lower parts cant fill the rest
+        if (rem - sum).abs() > max {
+            continue
+        }
+        operations.push_back(*i);
+        let res = try_resolve(sum, rem, steps - 1, operations);
+        if res {
+            return res
+        }
+        operations.pop_back();
+    }
+    false
+}
+
+// every line is a SNAFU number - convert them to decimal
+pub fn parse(lines: &[String]) -> Result<Vec<i64>, Error> {
+    let mut numbers = vec![];
+    for (y, line) in lines.iter().enumerate().filter(|(_, line)| !line.is_empty()) {
+        let mut digit = 0i64;
+        for (x, c) in line.chars().enumerate() {
+            let d = snafu_to_digit(c).ok_or(Error::unexpected_char(DAY, y, x, c))? as i64;
+            digit = digit.checked_mul(5)
+                .and_then(|digit| digit.checked_add(d))
+                .ok_or_else(|| Error::parse(DAY, y, "the number does not fit into 64 bits"))?;
+        }
+        numbers.push(digit);
+    }
+    // part1 only writes positive sums with at most 27 digits
+    let sum = numbers.iter().try_fold(0i64, |sum, n| sum.checked_add(*n));
+    match sum {
+        _ if numbers.is_empty() => Err(Error::input(DAY, "no SNAFU numbers")),
+        Some(sum) if sum > 0 && sum <= max_digit_for_mul(26) => Ok(numbers),
+        Some(sum) => Err(Error::input(DAY, &format!("the sum {} can not be written as SNAFU", sum))),
+        None => Err(Error::input(DAY, "the sum does not fit into 64 bits")),
+    }
+}
+
+pub fn part1(numbers: &[i64]) -> String {
+    // 20=022=21--=2--12=-2
+    let sum = numbers.iter().sum::<i64>();
+    // println!("{}", sum);
+
+    let mut mul = 0;
+    for x in 0..64 {
+        // let f = 5_i64.pow(x.try_into().unwrap());
+        let min = min_digit_for_mul(x);
+        let max = max_digit_for_mul(x);
+        // println!("{} {} {}", min, f, max);
+        if sum >= min && sum <= max {
+            mul = x;
+            break;
+        }
+    }
+
+    let f = 5_i64.pow(mul.try_into().unwrap());
+    let mut operations = VecDeque::new();
+    for i in [2, 1].iter() {
+        let rem = i * f;
+        operations.push_back(*i);
+        let res = try_resolve(sum, rem, (mul) as usize, &mut operations);
+        if res {
+            break
+        }
+        operations.pop_back();
+    }
+    let mut str = "".to_string();
+    for x in operations {
+        match x {
+            2 => str += "2",
+            1 => str += "1",
+            0 => str += "0",
+            -1 => str += "-",
+            -2 => str += "=",
+            _ => {}
+        }
+    }
+    str
+}
+
+pub fn part2(_numbers: &[i64]) -> String {
+    "".to_string()
+}
+
+pub struct Day25;
+
+impl Solver for Day25 {
+    type Input = Vec<i64>;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, Error> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, Error> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1};
+
+    #[test]
+    fn it_works() {
+        let lines = vec![
+            "1=-0-2", "12111", "2=0=", "21", "2=01", "111", "20012", "112", "1=-1=", "1-12", "12",
+            "1=", "122",
+        ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>();
+
+        let input = parse(&lines).unwrap();
+
+        let result = part1(&input);
+        assert_eq!(result, "2=-1=0");
+        // let result = part2(&input);
+        // assert_eq!(result, 20);
+
+        assert_eq!(parse(&[]).unwrap_err().to_string(), "day 25: no SNAFU numbers");
+        let lines = ["-", "="].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(parse(&lines).unwrap_err().to_string(), "day 25: the sum -3 can not be written as SNAFU");
+        let lines = ["2".repeat(28)];
+        assert_eq!(parse(&lines).unwrap_err().to_string(), "day 25 line 1: the number does not fit into 64 bits");
+    }
+}
@@ -0,0 +1,118 @@
+use std::collections::HashSet;
+use crate::solver::Solver;
+use crate::error::Error;
+
+const DAY: usize = 3;
+
+#[derive(Debug, Clone)]
+pub struct Rucksack {
+    pub items: String,
+}
+
+pub fn parse(lines: &[String]) -> Result<Vec<Rucksack>, Error> {
+    let mut rucksacks = vec![];
+    for (y, line) in lines.iter().enumerate().filter(|(_, line)| !line.is_empty()) {
+        if let Some((x, c)) = line.chars().enumerate().find(|(_, c)| !c.is_ascii_alphabetic()) {
+            return Err(Error::unexpected_char(DAY, y, x, c));
+        }
+        rucksacks.push(Rucksack { items: line.to_string() });
+    }
+    Ok(rucksacks)
+}
+
+pub fn part2(rucksacks: &[Rucksack]) -> Result<u32, String> {
+    // 2508
+    if rucksacks.is_empty() || !rucksacks.len().is_multiple_of(3) {
+        return Err(format!("expected groups of 3 rucksacks but got {}", rucksacks.len()));
+    }
+    let mut sum = 0u32;
+    for group in rucksacks.chunks(3) {
+        let rucksack1 = group[0].items.chars().collect::<HashSet<_>>();
+        let rucksack2 = group[1].items.chars().collect::<HashSet<_>>();
+        let rucksack3 = group[2].items.chars().collect::<HashSet<_>>();
+
+        let i1 = rucksack1.intersection(&rucksack2).cloned().collect::<HashSet<_>>();
+        let i2 = i1.intersection(&rucksack3).collect::<Vec<_>>();
+
+        for c in i2.iter() {
+            sum += get_priority(c)
+        }
+    }
+    Ok(sum)
+}
+
+fn get_priority(c: &char) -> u32 {
+    // Lowercase item types a through z have priorities 1 through 26.
+    // Uppercase item types A through Z have priorities 27 through 52.
+    if c.is_lowercase() {
+        c.to_ascii_lowercase() as u32 - 96
+    } else {
+        c.to_ascii_lowercase() as u32 - 96 + 26
+    }
+}
+
+pub fn part1(rucksacks: &[Rucksack]) -> u32 {
+    // 7691
+    let mut sum = 0u32;
+    for rucksack in rucksacks.iter() {
+        let line = &rucksack.items;
+        let rucksack_size = line.len() / 2;
+
+        let rucksack1 = line[0..rucksack_size].chars().collect::<HashSet<_>>();
+        let rucksack2 = line[rucksack_size..].chars().collect::<HashSet<_>>();
+        let intersection = rucksack1.intersection(&rucksack2).collect::<Vec<_>>();
+        // println!("{:?}", intersection);
+
+        for c in intersection.iter() {
+            sum += get_priority(c)
+        }
+    }
+    sum
+}
+
+pub struct Day3;
+
+impl Solver for Day3 {
+    type Input = Vec<Rucksack>;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, Error> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, Error> {
+        Ok(part1(input).to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, Error> {
+        part2(input).map(|sum| sum.to_string()).map_err(|e| Error::input(DAY, &e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solver::Solver;
+    use super::{parse, part1, part2, Day3};
+
+    #[test]
+    fn it_works() {
+        let lines = ["vJrwpWtwJgWrhcsFMMfFFhFp",
+                         "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+                         "PmmdzqPrVvPwwTWBwg",
+                         "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
+                         "ttgJtRGJQctTZtZT",
+                         "CrZsJsPPZsGzwwsLwLmpwMDw"].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let input = parse(&lines).unwrap();
+
+        let result = part1(&input);
+        assert_eq!(result, 157);
+        let result = part2(&input);
+        assert_eq!(result, Ok(70));
+
+        // part1 does not need whole groups
+        let input = parse(&lines[..2]).unwrap();
+        assert_eq!(part1(&input), 16 + 38);
+        let e = Day3::part2(&input).unwrap_err();
+        assert_eq!(e.to_string(), "day 3: expected groups of 3 rucksacks but got 2");
+        assert!(part2(&[]).is_err());
+    }
+}
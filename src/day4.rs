@@ -0,0 +1,103 @@
+use std::collections::HashSet;
+use crate::solver::Solver;
+use crate::error::{get_field, parse_number, Error};
+
+const DAY: usize = 4;
+
+#[derive(Debug, Clone)]
+pub struct SectionPair {
+    pub pair1: (u32, u32),
+    pub pair2: (u32, u32),
+}
+
+fn parse_section(y: usize, section: &str) -> Result<(u32, u32), Error> {
+    let range = section.split("-").collect::<Vec<&str>>();
+    let start = parse_number(DAY, y, get_field(DAY, y, &range, 0)?)?;
+    let end = parse_number(DAY, y, get_field(DAY, y, &range, 1)?)?;
+    Ok((start, end))
+}
+
+pub fn parse(lines: &[String]) -> Result<Vec<SectionPair>, Error> {
+    let mut pairs = vec![];
+
+    for (y, line) in lines.iter().enumerate() {
+        let sections = line.split(",").collect::<Vec<&str>>();
+        pairs.push(SectionPair {
+            pair1: parse_section(y, get_field(DAY, y, &sections, 0)?)?,
+            pair2: parse_section(y, get_field(DAY, y, &sections, 1)?)?,
+        });
+    }
+    Ok(pairs)
+}
+
+pub fn part2(pairs: &[SectionPair]) -> u32 {
+    // 895
+    let mut sum = 0u32;
+
+    for pair in pairs.iter() {
+        let (pair1_start, pair1_end) = pair.pair1;
+        let pair1_set =(pair1_start..pair1_end+1).collect::<HashSet<u32>>();
+
+        let (pair2_start, pair2_end) = pair.pair2;
+        let pair2_set =(pair2_start..pair2_end+1).collect::<HashSet<u32>>();
+
+        if pair1_set.intersection(&pair2_set).count() != 0  {
+            sum += 1
+        }
+    }
+    sum
+}
+
+pub fn part1(pairs: &[SectionPair]) -> u32 {
+    // 580
+    let mut sum = 0u32;
+
+    for pair in pairs.iter() {
+        let (pair1_start, pair1_end) = pair.pair1;
+        let (pair2_start, pair2_end) = pair.pair2;
+
+        if (pair1_start <= pair2_start && pair1_end >= pair2_end) || (pair2_start <= pair1_start && pair2_end >= pair1_end) {
+            sum += 1
+        }
+    }
+    sum
+}
+
+pub struct Day4;
+
+impl Solver for Day4 {
+    type Input = Vec<SectionPair>;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, Error> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, Error> {
+        Ok(part1(input).to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, Error> {
+        Ok(part2(input).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+
+    #[test]
+    fn it_works() {
+        let lines = ["2-4,6-8",
+                         "2-3,4-5",
+                         "5-7,7-9",
+                         "2-8,3-7",
+                         "6-6,4-6",
+                         "2-6,4-8"].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let input = parse(&lines).unwrap();
+
+        let result = part1(&input);
+        assert_eq!(result, 2);
+        let result = part2(&input);
+        assert_eq!(result, 4);
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::{HashMap, VecDeque};
+use regex::Regex;
+use itertools::Itertools;
+use crate::solver::Solver;
+use crate::error::{get_field, parse_number, Error};
+
+const DAY: usize = 5;
+
+#[derive(Debug, Clone)]
+pub struct Move {
+    pub amount: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Crates {
+    pub stacks: HashMap<usize, VecDeque<char>>,
+    pub moves: Vec<Move>,
+}
+
+pub fn parse(lines: &[String]) -> Result<Crates, Error> {
+    let mut crates = Crates {
+        stacks: HashMap::new(),
+        moves: vec![],
+    };
+
+    let re = Regex::new(r"\d+").unwrap(); // \d means digit
+    // the line of every move for the errors
+    let mut move_lines = vec![];
+    for (y, line) in lines.iter().enumerate() {
+        if line.starts_with("move") {
+            let move_numbers = re.find_iter(line).map(|m| m.as_str()).collect::<Vec<_>>();
+            let amount: usize = parse_number(DAY, y, get_field(DAY, y, &move_numbers, 0)?)?;
+            let from: usize = parse_number(DAY, y, get_field(DAY, y, &move_numbers, 1)?)?;
+            let to: usize = parse_number(DAY, y, get_field(DAY, y, &move_numbers, 2)?)?;
+            crates.moves.push(Move {
+                amount,
+                from,
+                to,
+            });
+            move_lines.push(y);
+        } else if line.trim_start().starts_with(|c: char| c.is_ascii_digit()) {
+            // the stack numbers below the crates - also creates the empty stacks
+            for n in line.split_whitespace() {
+                let key: usize = parse_number(DAY, y, n)?;
+                crates.stacks.entry(key).or_default();
+            }
+        } else if !line.is_empty() {
+            for (n, c) in line.chars().collect::<Vec<char>>().chunks(4).enumerate() {
+                if c.contains(&'[') {
+                    if c.len() < 3 || c[0] != '[' || c[2] != ']' {
+                        return Err(Error::parse(DAY, y, &format!("crate {} is cut off", n + 1)));
+                    }
+                    let key = n + 1;
+                    crates.stacks.entry(key)
+                        .or_default()
+                        .push_back(c[1]);
+                }
+            }
+        }
+    }
+    for m in crates.moves.iter() {
+        if !crates.stacks.contains_key(&m.from) || !crates.stacks.contains_key(&m.to) {
+            return Err(Error::input(DAY, &format!("move from {} to {} uses an unknown stack", m.from, m.to)));
+        }
+    }
+    // both parts move the same number of crates, only the order differs
+    let mut heights = crates.stacks.iter().map(|(key, stack)| (*key, stack.len())).collect::<HashMap<_, _>>();
+    for (m, y) in crates.moves.iter().zip(move_lines) {
+        let height = heights[&m.from];
+        if m.amount > height {
+            return Err(Error::parse(DAY, y, &format!("move {} from {} but it only holds {}", m.amount, m.from, height)));
+        }
+        *heights.get_mut(&m.from).unwrap() -= m.amount;
+        *heights.get_mut(&m.to).unwrap() += m.amount;
+    }
+    Ok(crates)
+}
+
+fn get_top_crates(stacks: &HashMap<usize, VecDeque<char>>) -> String {
+    let mut sum = "".to_string();
+    for (_, s) in stacks.iter().sorted() {
+        let c = s.front().unwrap_or(&' ');
+        sum.push(*c)
+    }
+    sum
+}
+
+pub fn part2(crates: &Crates) -> String {
+    // WDLPFNNNB
+    let mut stacks = crates.stacks.clone();
+
+    for m in crates.moves.iter() {
+        // move first one last to keep order
+        for i in (0..m.amount).rev() {
+            let c = stacks.get_mut(&m.from).unwrap().remove(i).unwrap();
+            stacks.get_mut(&m.to).unwrap().push_front(c);
+        }
+    }
+    get_top_crates(&stacks)
+}
+
+pub fn part1(crates: &Crates) -> String {
+    // ZBDRNPMVH
+    let mut stacks = crates.stacks.clone();
+
+    for m in crates.moves.iter() {
+        // move one at a time like normal stack
+        for _ in 0..m.amount {
+            let c = stacks.get_mut(&m.from).unwrap().pop_front().unwrap();
+            stacks.get_mut(&m.to).unwrap().push_front(c);
+        }
+    }
+    get_top_crates(&stacks)
+}
+
+pub struct Day5;
+
+impl Solver for Day5 {
+    type Input = Crates;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, Error> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, Error> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+
+    #[test]
+    fn it_works() {
+        let lines = ["    [D]    ",
+                         "[N] [C]    ",
+                         "[Z] [M] [P]",
+                         " 1   2   3 ",
+                         "",
+                         "move 1 from 2 to 1",
+                         "move 3 from 1 to 3",
+                         "move 2 from 2 to 1",
+                         "move 1 from 1 to 2"].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let input = parse(&lines).unwrap();
+
+        let result = part1(&input);
+        assert_eq!(result, "CMZ");
+        let result = part2(&input);
+        assert_eq!(result, "MCD");
+
+        let mut cut_off = lines.clone();
+        cut_off[2] = "[Z] [M] [".to_string();
+        assert_eq!(parse(&cut_off).unwrap_err().to_string(), "day 5 line 3: crate 3 is cut off");
+        let mut too_many = lines.clone();
+        too_many[7] = "move 3 from 2 to 1".to_string();
+        assert_eq!(parse(&too_many).unwrap_err().to_string(), "day 5 line 8: move 3 from 2 but it only holds 2");
+    }
+}
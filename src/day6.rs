@@ -0,0 +1,83 @@
+use itertools::Itertools;
+use crate::solver::Solver;
+use crate::error::Error;
+
+const DAY: usize = 6;
+
+#[derive(Debug, Clone)]
+pub struct Datastream {
+    pub signal: String,
+}
+
+pub fn parse(lines: &[String]) -> Result<Datastream, Error> {
+    let signal = lines.first().ok_or_else(|| Error::input(DAY, "empty input"))?;
+    Ok(Datastream {
+        signal: signal.to_string(),
+    })
+}
+
+pub fn part2(datastream: &Datastream) -> u32 {
+    // 2472
+    let line = &datastream.signal;
+    let mut sum = 0u32;
+
+    for (start, i) in (13..line.len()).enumerate() {
+        let chunk = line.get(start..i + 1).unwrap();
+        if chunk.chars().all_unique() {
+            sum = start as u32 + 14;
+            break;
+        }
+    }
+    sum
+}
+
+
+pub fn part1(datastream: &Datastream) -> u32 {
+    // 1034
+    let line = &datastream.signal;
+    let mut sum = 0u32;
+
+    for (start, i) in (3..line.len()).enumerate() {
+        let chunk = line.get(start..i + 1).unwrap();
+        if chunk.chars().all_unique() {
+            sum = start as u32 + 4;
+            break;
+        }
+    }
+    sum
+}
+
+pub struct Day6;
+
+impl Solver for Day6 {
+    type Input = Datastream;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, Error> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, Error> {
+        Ok(part1(input).to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, Error> {
+        Ok(part2(input).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+
+    #[test]
+    fn it_works() {
+        let lines = ["mjqjpqmgbljsphdztnvjfqwrcgsmlb"].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        let input = parse(&lines).unwrap();
+
+        let result = part1(&input);
+        assert_eq!(result, 7);
+        let result = part2(&input);
+        assert_eq!(result, 19);
+    }
+}
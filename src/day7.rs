@@ -0,0 +1,276 @@
+use std::collections::{HashSet};
+use crate::solver::Solver;
+use crate::error::{parse_number, Error};
+
+const DAY: usize = 7;
+
+const TOTAL_SIZE: u32 = 70000000;
+const NEEDED_FREE_SIZE: u32 = 30000000;
+
+#[derive(Debug)]
+pub struct File {
+    pub size: u32,
+    pub name: String,
+}
+
+#[derive(Debug)]
+pub struct Dir {
+    pub name: String,
+    pub files: Vec<File>,
+    pub dirs: Vec<Dir>,
+}
+
+#[derive(Debug)]
+pub struct FileSystem {
+    pub root: Dir,
+    // every visited dir as full path starting with "/"
+    pub dir_list: HashSet<Vec<String>>,
+}
+
+impl File {
+    fn new(size: u32, n: &str) -> File {
+        File {
+            size,
+            name: n.to_string(),
+        }
+    }
+}
+
+impl Dir {
+    fn new(n: &str) -> Dir {
+        Dir {
+            name: n.to_string(),
+            files: vec![],
+            dirs: vec![],
+        }
+    }
+    fn add_file(&mut self, file: File) {
+        self.files.push(file)
+    }
+
+    fn add_dir(&mut self, dir: Dir) {
+        self.dirs.push(dir)
+    }
+}
+
+
+fn calc_size(dir: &Dir, size: u32) -> u32 {
+    let mut s = size;
+    for f in dir.files.iter() {
+        s += f.size;
+    }
+    for d in dir.dirs.iter() {
+        s = calc_size(d, s);
+    }
+    s
+}
+
+#[allow(dead_code)]
+fn print_tree(dir: &Dir, level: usize) {
+    if level == 0 {
+        println!("- / (dir)")
+    }
+    for d in dir.dirs.iter() {
+        println!("{number:indent$}{number} - {dir} (dir)", number = " ", indent = level + 1, dir = d.name);
+        print_tree(d, level + 1)
+    }
+    for f in dir.files.iter() {
+        println!("{number:indent$}{number} - {file} (file, {size})", number = " ", indent = level + 2, file = f.name, size = f.size);
+    }
+}
+
+fn cwd_to_dir<'a>(root: &'a mut Dir, current_dir: &'a mut Dir, cwd: &'a Vec<String>, level: usize) -> &'a mut Dir {
+    if level == cwd.len() - 1 {
+        return current_dir;
+    }
+    let next = cwd.get(level + 1).unwrap();
+    for d in current_dir.dirs.iter_mut() {
+        if d.name == *next {
+            return cwd_to_dir(root, d, cwd, level + 1);
+        }
+    }
+
+    root
+}
+
+// same as cwd_to_dir but only for reading
+fn get_dir<'a>(current_dir: &'a Dir, cwd: &[String], level: usize) -> Option<&'a Dir> {
+    if level == cwd.len() - 1 {
+        return Some(current_dir);
+    }
+    let next = cwd.get(level + 1).unwrap();
+    for d in current_dir.dirs.iter() {
+        if d.name == *next {
+            return get_dir(d, cwd, level + 1);
+        }
+    }
+    None
+}
+
+pub fn parse(lines: &[String]) -> Result<FileSystem, Error> {
+    let mut root = Dir::new("/");
+    let mut empty_root = Dir::new("/");
+    let mut dir_list = HashSet::new();
+    let mut cwd = vec!["/".to_string()];
+    dir_list.insert(cwd.clone());
+
+    for (y, line) in lines.iter().enumerate() {
+        if line.starts_with("$ ls") {
+            // println!("{:?}", cwd);
+        } else if line.contains("$ cd") {
+            let dir = line.split_whitespace().nth(2).ok_or_else(|| Error::parse(DAY, y, "cd without a directory"))?;
+            match dir {
+                ".." => {
+                    if cwd.len() == 1 {
+                        return Err(Error::parse(DAY, y, "cd .. in the root dir"));
+                    }
+                    cwd.pop();
+                }
+                "/" => {
+                    cwd.clear();
+                    cwd.push("/".to_string());
+                }
+                _ => {
+                    cwd.push(dir.to_string());
+                    dir_list.insert(cwd.clone());
+                }
+            }
+        } else {
+            let current_dir: &mut Dir = cwd_to_dir(&mut empty_root, &mut root, &cwd, 0);
+
+            let s = line.split_whitespace().collect::<Vec<_>>();
+            if s.is_empty() {
+                continue;
+            }
+            if s.len() != 2 {
+                return Err(Error::parse(DAY, y, &format!("unexpected listing '{}'", line)));
+            }
+            if s[0] == "dir" {
+                current_dir.add_dir(Dir::new(s[1]))
+            } else {
+                let size: u32 = parse_number(DAY, y, s[0])?;
+                if size != 0 {
+                    current_dir.add_file(File::new(size, s[1]))
+                }
+            }
+        }
+    }
+
+    Ok(FileSystem {
+        root,
+        dir_list,
+    })
+}
+
+pub fn part1(fs: &FileSystem) -> u32 {
+    // 1306611
+    let mut sum = 0u32;
+
+    // without the root dir
+    for c in fs.dir_list.iter().filter(|c| c.len() > 1) {
+        let e = get_dir(&fs.root, c, 0).unwrap();
+        let size = calc_size(e, 0);
+        if size <= 100000 {
+            sum += size
+        }
+    }
+    sum
+}
+
+
+pub fn part2(fs: &FileSystem) -> Result<u32, String> {
+    // 13210366
+    let mut sum = 0u32;
+
+    let used_size = calc_size(&fs.root, 0);
+    if used_size > TOTAL_SIZE {
+        return Err(format!("the files take {} but the disk only has {}", used_size, TOTAL_SIZE));
+    }
+    if TOTAL_SIZE - used_size >= NEEDED_FREE_SIZE {
+        return Err(format!("the files take only {}, there is no need to delete anything", used_size));
+    }
+
+    let need_to_free_space =  NEEDED_FREE_SIZE - (TOTAL_SIZE - used_size);
+    // println!("need_to_free_space = {}", need_to_free_space);
+
+    let mut possible_delete_size = vec![];
+    for c in fs.dir_list.iter() {
+        let e = get_dir(&fs.root, c, 0).unwrap();
+        let size = calc_size(e, 0);
+        if size > need_to_free_space {
+            possible_delete_size.push(size);
+        }
+    }
+    possible_delete_size.sort();
+    sum += *possible_delete_size.first().unwrap();
+
+    Ok(sum)
+}
+
+pub struct Day7;
+
+impl Solver for Day7 {
+    type Input = FileSystem;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, Error> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, Error> {
+        Ok(part1(input).to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, Error> {
+        part2(input).map(|size| size.to_string()).map_err(|e| Error::input(DAY, &e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solver::Solver;
+    use super::{parse, part1, part2, Day7};
+
+    #[test]
+    fn it_works() {
+        let lines = vec!["$ cd /",
+                         "$ ls",
+                         "dir a",
+                         "14848514 b.txt",
+                         "8504156 c.dat",
+                         "dir d",
+                         "$ cd a",
+                         "$ ls",
+                         "dir e",
+                         "29116 f",
+                         "2557 g",
+                         "62596 h.lst",
+                         "$ cd e",
+                         "$ ls",
+                         "584 i",
+                         "$ cd ..",
+                         "$ cd ..",
+                         "$ cd d",
+                         "$ ls",
+                         "4060174 j",
+                         "8033020 d.log",
+                         "5626152 d.ext",
+                         "7214296 k"].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        let input = parse(&lines).unwrap();
+
+        let result = part1(&input);
+        assert_eq!(result, 95437);
+        let result = part2(&input);
+        assert_eq!(result, Ok(24933642));
+
+        // part1 has an answer even if part2 has none
+        let input = parse(&lines[..5]).unwrap();
+        assert_eq!(part1(&input), 0);
+        let e = Day7::part2(&input).unwrap_err();
+        assert_eq!(e.to_string(), "day 7: the files take only 23352670, there is no need to delete anything");
+        assert!(part2(&parse(&[]).unwrap()).is_err());
+        let lines = ["$ cd /", "$ ls", "70000001 big"].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let e = part2(&parse(&lines).unwrap()).unwrap_err();
+        assert_eq!(e, "the files take 70000001 but the disk only has 70000000");
+    }
+}
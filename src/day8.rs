@@ -0,0 +1,193 @@
+use crate::solver::Solver;
+use crate::error::Error;
+use crate::grid::Grid;
+
+const DAY: usize = 8;
+
+#[derive(Debug, Clone)]
+pub struct Forest {
+    pub trees: Grid<u32>,
+}
+
+pub fn parse(lines: &[String]) -> Result<Forest, Error> {
+    let grid = Grid::parse(DAY, lines, |_, c| c.to_digit(10u32))?;
+    if grid.is_empty() {
+        return Err(Error::input(DAY, "the forest is empty"));
+    }
+    // the solution only works for a square forest
+    if grid.width() != grid.height() {
+        return Err(Error::input(DAY, "the forest must be square"));
+    }
+    Ok(Forest { trees: grid })
+}
+
+pub fn part1(forest: &Forest) -> u32 {
+    // 1814
+    let mut sum = 0u32;
+    let grid = &forest.trees;
+    let grid_size = grid.height();
+
+    // edge trees are always visible
+    sum += (grid_size as u32 * 4) - 4;
+
+    for y in 1..grid_size - 1 {
+        for x in 1..grid_size - 1 {
+            if is_visible(grid, (x, y)) {
+                sum += 1
+            }
+        }
+    }
+    sum
+}
+
+fn get_line(grid: &Grid<u32>, y: usize) -> Vec<u32> {
+    grid.row(y as i32).map(|(_, height)| *height).collect()
+}
+
+fn get_column(grid: &Grid<u32>, x: usize) -> Vec<u32> {
+    grid.column(x as i32).map(|(_, height)| *height).collect()
+}
+
+fn is_visible(grid: &Grid<u32>, tree: (usize, usize)) -> bool {
+    let x = tree.0;
+    let y = tree.1;
+    let tree_height = grid.get((x as i32, y as i32)).unwrap();
+
+    let line = get_line(grid, y);
+    let column = get_column(grid, x);
+
+    if line.get(..x).unwrap().iter().filter(|height| height >= &tree_height).count() == 0 {
+        return true;
+    }
+    if line.get(x + 1..).unwrap().iter().filter(|height| height >= &tree_height).count() == 0 {
+        return true;
+    }
+    if column.get(..y).unwrap().iter().filter(|height| height >= &tree_height).count() == 0 {
+        return true;
+    }
+    if column.get(y + 1..).unwrap().iter().filter(|height| height >= &tree_height).count() == 0 {
+        return true;
+    }
+    false
+}
+
+fn get_view_distances(grid: &Grid<u32>, tree: (usize, usize)) -> Vec<usize> {
+    let mut distances = vec![];
+
+    let tree_x = tree.0;
+    let tree_y = tree.1;
+    let tree_height = grid.get((tree_x as i32, tree_y as i32)).unwrap();
+
+    let line = get_line(grid, tree_y);
+    let column = get_column(grid, tree_x);
+
+    let mut up_distance = 0;
+    for y in (0..tree_y).rev() {
+        let h = column.get(y).unwrap();
+        if h < tree_height {
+            up_distance += 1;
+        } else {
+            up_distance += 1;
+            break;
+        }
+    }
+    distances.push(up_distance);
+
+    let mut left_distance = 0;
+    for x in (0..tree_x).rev() {
+        let h = line.get(x).unwrap();
+        if h < tree_height {
+            left_distance += 1;
+        } else {
+            left_distance += 1;
+            break;
+        }
+    }
+    distances.push(left_distance);
+
+    let mut down_distance = 0;
+    for y in tree_y + 1..grid.height() {
+        let h = column.get(y).unwrap();
+        if h < tree_height {
+            down_distance += 1;
+        } else {
+            down_distance += 1;
+            break;
+        }
+    }
+    distances.push(down_distance);
+
+    let mut right_distance = 0;
+    for x in tree_x + 1..grid.width() {
+        let h = line.get(x).unwrap();
+        if h < tree_height {
+            right_distance += 1;
+        } else {
+            right_distance += 1;
+            break;
+        }
+    }
+    distances.push(right_distance);
+
+    distances
+}
+
+pub fn part2(forest: &Forest) -> u32 {
+    // 330786
+    let grid = &forest.trees;
+    let grid_size = grid.height();
+
+    let mut max_score = 0u32;
+    for y in 0..grid_size {
+        for x in 0..grid_size {
+            let distances = get_view_distances(grid, (x, y));
+            let score = distances.iter().product::<usize>() as u32;
+            if score > max_score {
+                max_score = score
+            }
+        }
+    }
+
+    max_score
+}
+
+pub struct Day8;
+
+impl Solver for Day8 {
+    type Input = Forest;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, Error> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, Error> {
+        Ok(part1(input).to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, Error> {
+        Ok(part2(input).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+
+    #[test]
+    fn it_works() {
+        let lines = ["30373",
+                         "25512",
+                         "65332",
+                         "33549",
+                         "35390"].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        let input = parse(&lines).unwrap();
+
+        let result = part1(&input);
+        assert_eq!(result, 21);
+        let result = part2(&input);
+        assert_eq!(result, 8);
+
+        assert_eq!(parse(&[]).unwrap_err().to_string(), "day 8: the forest is empty");
+    }
+}
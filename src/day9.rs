@@ -0,0 +1,200 @@
+use std::collections::HashSet;
+use crate::solver::Solver;
+use crate::error::{get_field, parse_number, Error};
+
+const DAY: usize = 9;
+
+#[derive(Debug, Copy, Clone)]
+pub enum Direction {
+    Up = 0,
+    Down = 1,
+    Left = 2,
+    Right = 3,
+}
+
+impl TryFrom<&str> for Direction {
+    type Error = String;
+
+    fn try_from(c: &str) -> Result<Self, Self::Error> {
+        match c {
+            "U" => Ok(Direction::Up),
+            "D" => Ok(Direction::Down),
+            "L" => Ok(Direction::Left),
+            "R" => Ok(Direction::Right),
+            _ => Err(format!("unexpected direction '{}'", c)),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Motion {
+    pub direction: Direction,
+    pub steps: usize,
+}
+
+pub fn parse(lines: &[String]) -> Result<Vec<Motion>, Error> {
+    let mut motions = vec![];
+    for (y, line) in lines.iter().enumerate() {
+        let step: Vec<_> = line.split_whitespace().collect();
+        let direction = get_field(DAY, y, &step, 0)?;
+        let d = Direction::try_from(direction).map_err(|e| Error::parse(DAY, y, &e))?;
+        let steps: usize = parse_number(DAY, y, get_field(DAY, y, &step, 1)?)?;
+        motions.push(Motion { direction: d, steps });
+    }
+    Ok(motions)
+}
+
+fn move_head(pos: (i32, i32), d: &Direction) -> (i32, i32) {
+    match d {
+        Direction::Up => (pos.0, pos.1 + 1),
+        Direction::Down => (pos.0, pos.1 - 1),
+        Direction::Left => (pos.0 - 1, pos.1),
+        Direction::Right => (pos.0 + 1, pos.1),
+    }
+}
+
+fn tail_must_move(head_pos: (i32, i32), tail_pos: (i32, i32)) -> bool {
+    head_pos.0.abs_diff(tail_pos.0) > 1 || head_pos.1.abs_diff(tail_pos.1) > 1
+}
+fn move_tail(head_pos: (i32, i32), tail_pos: (i32, i32)) -> (i32, i32) {
+    if tail_must_move(head_pos, tail_pos) {
+        let mut new_x = tail_pos.0;
+        let mut new_y = tail_pos.1;
+
+        if head_pos.0 > tail_pos.0 {
+            new_x += 1;
+        } else if head_pos.0 < tail_pos.0 {
+            new_x -= 1;
+        }
+
+        if head_pos.1 > tail_pos.1 {
+            new_y += 1;
+        } else if head_pos.1 < tail_pos.1 {
+            new_y -= 1;
+        }
+        (new_x, new_y)
+    } else {
+        tail_pos
+    }
+}
+
+pub fn part1(motions: &[Motion]) -> u32 {
+    // 5883
+    let mut head = (0i32, 0i32);
+    let mut tail = (0i32, 0i32);
+    let mut path = HashSet::new();
+
+    for motion in motions.iter() {
+        let d = motion.direction;
+        let steps = motion.steps;
+        for _ in 0..steps {
+            head = move_head(head, &d);
+            tail = move_tail(head, tail);
+            path.insert(tail);
+        }
+    }
+    path.len() as u32
+}
+
+#[allow(dead_code)]
+fn print_grid(snake: &[(i32, i32)]) {
+    for y in -20..20 {
+        for x in -20..20 {
+            if x == 0 && y == 0 {
+                print!("s")
+            } else {
+                if snake.contains(&(x, -y)) {
+                    print!("*")
+                } else {
+                    print!(".")
+                }
+            }
+        }
+        println!();
+    }
+}
+
+pub fn part2(motions: &[Motion]) -> u32 {
+    // 2367
+    let mut path = HashSet::new();
+    let mut snake = vec![(0i32, 0i32); 10];
+
+    for motion in motions.iter() {
+        let d = motion.direction;
+        let steps = motion.steps;
+
+        for _ in 0..steps {
+            let mut new_snake = vec![];
+            let mut head = *snake.first().unwrap();
+
+            head = move_head(head, &d);
+            new_snake.push(head);
+
+            for knot in snake.get(1..).unwrap().iter() {
+                let new_knot = move_tail(head, *knot);
+                new_snake.push(new_knot);
+                head = new_knot;
+            }
+
+            snake.clear();
+            for knot in new_snake {
+                snake.push(knot);
+            }
+            path.insert(*snake.last().unwrap());
+        }
+    }
+    path.len() as u32
+}
+
+pub struct Day9;
+
+impl Solver for Day9 {
+    type Input = Vec<Motion>;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, Error> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, Error> {
+        Ok(part1(input).to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, Error> {
+        Ok(part2(input).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+
+    #[test]
+    fn it_works() {
+        let lines = ["R 4",
+                         "U 4",
+                         "L 3",
+                         "D 1",
+                         "R 4",
+                         "D 1",
+                         "L 5",
+                         "R 2"].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        let input = parse(&lines).unwrap();
+
+        let result = part1(&input);
+        assert_eq!(result, 13);
+
+        let lines1 = ["R 5",
+                          "U 8",
+                          "L 8",
+                          "D 3",
+                          "R 17",
+                          "D 10",
+                          "L 25",
+                          "U 20"].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let input = parse(&lines1).unwrap();
+
+        let result = part2(&input);
+        assert_eq!(result, 36);
+    }
+}
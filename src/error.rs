@@ -0,0 +1,98 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+// everything that can go wrong reading a puzzle input
+// line and column are 1 based like in an editor
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    // a single line of the input is malformed
+    Parse {
+        day: usize,
+        line: usize,
+        column: Option<usize>,
+        description: String,
+    },
+    // the input as a whole does not fit - missing lines, empty input, ...
+    Input {
+        day: usize,
+        description: String,
+    },
+}
+
+impl Error {
+    // line_idx and col_idx are 0 based like the ones from enumerate()
+    pub fn unexpected_char(day: usize, line_idx: usize, col_idx: usize, c: char) -> Error {
+        Error::Parse {
+            day,
+            line: line_idx + 1,
+            column: Some(col_idx + 1),
+            description: format!("unexpected character '{}'", c),
+        }
+    }
+
+    pub fn parse(day: usize, line_idx: usize, description: &str) -> Error {
+        Error::Parse {
+            day,
+            line: line_idx + 1,
+            column: None,
+            description: description.to_string(),
+        }
+    }
+
+    pub fn input(day: usize, description: &str) -> Error {
+        Error::Input {
+            day,
+            description: description.to_string(),
+        }
+    }
+
+    pub fn day(&self) -> usize {
+        match self {
+            Error::Parse { day, .. } => *day,
+            Error::Input { day, .. } => *day,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parse { day, line, column: Some(column), description } => {
+                write!(f, "day {} line {}: {} at column {}", day, line, description, column)
+            }
+            Error::Parse { day, line, column: None, description } => {
+                write!(f, "day {} line {}: {}", day, line, description)
+            }
+            Error::Input { day, description } => {
+                write!(f, "day {}: {}", day, description)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+// parse a number of a line or report which line is broken
+pub fn parse_number<T: FromStr>(day: usize, line_idx: usize, s: &str) -> Result<T, Error> {
+    s.trim().parse().map_err(|_| Error::parse(day, line_idx, &format!("invalid number '{}'", s.trim())))
+}
+
+// the n-th field of a split line or report it is missing
+pub fn get_field<'a>(day: usize, line_idx: usize, fields: &[&'a str], n: usize) -> Result<&'a str, Error> {
+    fields.get(n).copied().ok_or_else(|| Error::parse(day, line_idx, &format!("missing field {}", n + 1)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_number, Error};
+
+    #[test]
+    fn it_works() {
+        let e = Error::unexpected_char(22, 200, 4, 'x');
+        assert_eq!(e.to_string(), "day 22 line 201: unexpected character 'x' at column 5");
+        let e = Error::parse(1, 0, "unexpected character 'x'");
+        assert_eq!(e.to_string(), "day 1 line 1: unexpected character 'x'");
+        assert_eq!(parse_number::<u32>(1, 2, "12"), Ok(12));
+        assert_eq!(parse_number::<u32>(1, 2, "1x").unwrap_err().to_string(), "day 1 line 3: invalid number '1x'");
+    }
+}
@@ -0,0 +1,314 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+use crate::Error;
+
+// (x, y) with y going down like the lines of the input
+pub type Pos = (i32, i32);
+
+const NEIGHBOURS4: [Pos; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+const NEIGHBOURS8: [Pos; 8] = [(-1, -1), (0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0)];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Backend<T> {
+    // every cell of width x height starting at (0, 0)
+    Dense {
+        cells: Vec<T>,
+        width: usize,
+        height: usize,
+    },
+    // only the cells that were set, anywhere
+    // bounds are kept up to date so that width and height do not scan all cells
+    Sparse {
+        cells: HashMap<Pos, T>,
+        bounds: Option<(Pos, Pos)>,
+    },
+}
+
+// min and max of all positions, both inclusive
+fn scan_bounds<'a>(mut positions: impl Iterator<Item = &'a Pos>) -> Option<(Pos, Pos)> {
+    let first = *positions.next()?;
+    Some(positions.fold((first, first), |bounds, pos| extend_bounds(bounds, *pos)))
+}
+
+fn extend_bounds((min, max): (Pos, Pos), pos: Pos) -> (Pos, Pos) {
+    ((min.0.min(pos.0), min.1.min(pos.1)), (max.0.max(pos.0), max.1.max(pos.1)))
+}
+
+// a 2d map of cells
+// dense grids have a fixed size and are what a char map of the input becomes,
+// sparse grids grow in every direction and are good for a few cells on a big area
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    backend: Backend<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            backend: Backend::Dense {
+                cells: vec![fill; width * height],
+                width,
+                height,
+            },
+        }
+    }
+}
+
+impl<T> Default for Grid<T> {
+    fn default() -> Self {
+        Grid::sparse()
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn sparse() -> Grid<T> {
+        Grid {
+            backend: Backend::Sparse {
+                cells: HashMap::new(),
+                bounds: None,
+            },
+        }
+    }
+
+    // a dense grid of a char map, empty lines are skipped
+    // f returns None for a char it does not know and the char gets reported
+    pub fn parse<F>(day: usize, lines: &[String], mut f: F) -> Result<Grid<T>, Error>
+    where
+        F: FnMut(Pos, char) -> Option<T>,
+    {
+        let mut cells = vec![];
+        let mut width = 0;
+        let mut height = 0;
+        for (line_idx, line) in lines.iter().enumerate().filter(|(_, line)| !line.is_empty()) {
+            let len = line.chars().count();
+            if height == 0 {
+                width = len;
+            } else if len != width {
+                return Err(Error::parse(day, line_idx, &format!("expected {} columns but got {}", width, len)));
+            }
+            for (x, c) in line.chars().enumerate() {
+                let cell = f((x as i32, height as i32), c).ok_or(Error::unexpected_char(day, line_idx, x, c))?;
+                cells.push(cell);
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            backend: Backend::Dense { cells, width, height },
+        })
+    }
+
+    pub fn is_sparse(&self) -> bool {
+        matches!(self.backend, Backend::Sparse { .. })
+    }
+
+    // min and max position, both inclusive - None for an empty sparse grid
+    pub fn bounds(&self) -> Option<(Pos, Pos)> {
+        match &self.backend {
+            Backend::Dense { width, height, .. } => {
+                if *width == 0 || *height == 0 {
+                    return None;
+                }
+                Some(((0, 0), (*width as i32 - 1, *height as i32 - 1)))
+            }
+            Backend::Sparse { bounds, .. } => *bounds,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.bounds().map(|(min, max)| (max.0 - min.0 + 1) as usize).unwrap_or(0)
+    }
+
+    pub fn height(&self) -> usize {
+        self.bounds().map(|(min, max)| (max.1 - min.1 + 1) as usize).unwrap_or(0)
+    }
+
+    // a sparse grid has no border, every position is inside
+    pub fn in_bounds(&self, pos: Pos) -> bool {
+        match &self.backend {
+            Backend::Dense { width, height, .. } => {
+                pos.0 >= 0 && pos.1 >= 0 && (pos.0 as usize) < *width && (pos.1 as usize) < *height
+            }
+            Backend::Sparse { .. } => true,
+        }
+    }
+
+    fn index(&self, pos: Pos) -> Option<usize> {
+        match &self.backend {
+            Backend::Dense { width, .. } if self.in_bounds(pos) => Some(pos.1 as usize * width + pos.0 as usize),
+            _ => None,
+        }
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        match &self.backend {
+            Backend::Dense { cells, .. } => self.index(pos).map(|idx| &cells[idx]),
+            Backend::Sparse { cells, .. } => cells.get(&pos),
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        let idx = self.index(pos);
+        match &mut self.backend {
+            Backend::Dense { cells, .. } => idx.map(|idx| &mut cells[idx]),
+            Backend::Sparse { cells, .. } => cells.get_mut(&pos),
+        }
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.get(pos).is_some()
+    }
+
+    // panics outside of a dense grid like indexing a Vec
+    pub fn set(&mut self, pos: Pos, value: T) {
+        let idx = self.index(pos);
+        match &mut self.backend {
+            Backend::Dense { cells, width, height } => {
+                let idx = idx.unwrap_or_else(|| panic!("{:?} is outside of the {}x{} grid", pos, width, height));
+                cells[idx] = value;
+            }
+            Backend::Sparse { cells, bounds } => {
+                cells.insert(pos, value);
+                *bounds = Some(bounds.map_or((pos, pos), |bounds| extend_bounds(bounds, pos)));
+            }
+        }
+    }
+
+    // only sparse grids can lose cells, panics for a dense grid like set outside of it
+    pub fn remove(&mut self, pos: Pos) -> Option<T> {
+        match &mut self.backend {
+            Backend::Dense { .. } => panic!("can not remove {:?} of a dense grid", pos),
+            Backend::Sparse { cells, bounds } => {
+                let cell = cells.remove(&pos)?;
+                // only a cell on the border can make the bounds shrink
+                if let Some((min, max)) = *bounds {
+                    if pos.0 == min.0 || pos.0 == max.0 || pos.1 == min.1 || pos.1 == max.1 {
+                        *bounds = scan_bounds(cells.keys());
+                    }
+                }
+                Some(cell)
+            }
+        }
+    }
+
+    // panics for a dense grid
+    pub fn clear(&mut self) {
+        match &mut self.backend {
+            Backend::Dense { .. } => panic!("can not clear a dense grid"),
+            Backend::Sparse { cells, bounds } => {
+                cells.clear();
+                *bounds = None;
+            }
+        }
+    }
+
+    // number of set cells
+    pub fn len(&self) -> usize {
+        match &self.backend {
+            Backend::Dense { cells, .. } => cells.len(),
+            Backend::Sparse { cells, .. } => cells.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // dense grids row by row, sparse grids in no particular order
+    pub fn iter(&self) -> Box<dyn Iterator<Item = (Pos, &T)> + '_> {
+        match &self.backend {
+            Backend::Dense { cells, width, .. } => {
+                let width = *width;
+                Box::new(cells.iter().enumerate().map(move |(idx, cell)| (((idx % width) as i32, (idx / width) as i32), cell)))
+            }
+            Backend::Sparse { cells, .. } => Box::new(cells.iter().map(|(pos, cell)| (*pos, cell))),
+        }
+    }
+
+    // the set cells of a row from left to right
+    pub fn row(&self, y: i32) -> impl Iterator<Item = (Pos, &T)> + '_ {
+        let (min, max) = self.bounds().unwrap_or(((0, 0), (-1, -1)));
+        (min.0..max.0 + 1).filter_map(move |x| self.get((x, y)).map(|cell| ((x, y), cell)))
+    }
+
+    // the set cells of a column from top to bottom
+    pub fn column(&self, x: i32) -> impl Iterator<Item = (Pos, &T)> + '_ {
+        let (min, max) = self.bounds().unwrap_or(((0, 0), (-1, -1)));
+        (min.1..max.1 + 1).filter_map(move |y| self.get((x, y)).map(|cell| ((x, y), cell)))
+    }
+
+    // up, right, down, left - only the ones inside the grid
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS4.iter().map(move |d| (pos.0 + d.0, pos.1 + d.1)).filter(|pos| self.in_bounds(*pos))
+    }
+
+    // clockwise starting at the upper left - only the ones inside the grid
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS8.iter().map(move |d| (pos.0 + d.0, pos.1 + d.1)).filter(|pos| self.in_bounds(*pos))
+    }
+}
+
+// unset cells of a sparse grid are shown as '.'
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some((min, max)) = self.bounds() {
+            for y in min.1..max.1 + 1 {
+                for x in min.0..max.0 + 1 {
+                    match self.get((x, y)) {
+                        Some(cell) => write!(f, "{}", cell)?,
+                        None => write!(f, ".")?,
+                    }
+                }
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+
+    #[test]
+    fn it_works() {
+        let lines = ["123",
+                     "456"].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let grid = Grid::parse(1, &lines, |_, c| c.to_digit(10)).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get((2, 1)), Some(&6));
+        assert_eq!(grid.get((3, 1)), None);
+        assert_eq!(grid.neighbours4((0, 0)).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours8((1, 0)).count(), 5);
+        assert_eq!(grid.row(1).map(|(_, c)| *c).collect::<Vec<_>>(), vec![4, 5, 6]);
+        assert_eq!(grid.column(2).map(|(_, c)| *c).collect::<Vec<_>>(), vec![3, 6]);
+        assert_eq!(grid.to_string(), "123\n456\n");
+
+        let lines = ["12", "4x"].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let e = Grid::parse(1, &lines, |_, c| c.to_digit(10)).unwrap_err();
+        assert_eq!(e.to_string(), "day 1 line 2: unexpected character 'x' at column 2");
+        let lines = ["12", "4"].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let e = Grid::parse(1, &lines, |_, c| c.to_digit(10)).unwrap_err();
+        assert_eq!(e.to_string(), "day 1 line 2: expected 2 columns but got 1");
+
+        let mut grid = Grid::sparse();
+        grid.set((-2, 5), '#');
+        grid.set((1, 6), '#');
+        assert_eq!(grid.bounds(), Some(((-2, 5), (1, 6))));
+        assert_eq!(grid.neighbours4((0, 0)).count(), 4);
+        assert_eq!(grid.to_string(), "#...\n...#\n");
+        assert_eq!(grid.remove((1, 6)), Some('#'));
+        assert_eq!(grid.bounds(), Some(((-2, 5), (-2, 5))));
+        assert_eq!(grid.remove((1, 6)), None);
+        grid.set((3, 3), '#');
+        grid.set((0, 4), '#');
+        assert_eq!(grid.remove((0, 4)), Some('#'));
+        assert_eq!((grid.width(), grid.height()), (6, 3));
+        grid.clear();
+        assert_eq!(grid.bounds(), None);
+
+        // dense grids keep all their cells
+        assert!(std::panic::catch_unwind(|| Grid::new(2, 2, 0).remove((0, 0))).is_err());
+        assert!(std::panic::catch_unwind(|| Grid::new(2, 2, 0).clear()).is_err());
+    }
+}
@@ -0,0 +1,100 @@
+use std::env;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+
+// overrides the default input dir
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+pub const INPUT_DIR: &str = "input";
+pub const EXAMPLE_DIR: &str = "input_test";
+
+// where the lines of a puzzle come from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Input::Stdin => { write!(f, "<stdin>") }
+            Input::File(path) => { write!(f, "{}", path.display()) }
+        }
+    }
+}
+
+impl Input {
+    pub fn read_lines(&self) -> io::Result<Vec<String>> {
+        match self {
+            Input::Stdin => io::stdin().lock().lines().collect(),
+            Input::File(path) => {
+                let file = std::fs::File::open(path)?;
+                io::BufReader::new(file).lines().collect()
+            }
+        }
+    }
+}
+
+pub fn input_file_name(day: usize) -> String {
+    format!("input_day{}.txt", day)
+}
+
+pub fn example_file_name(day: usize) -> String {
+    format!("input_day{}_test.txt", day)
+}
+
+// relative paths are tried in the working directory first and then
+// in the crate root so the binaries also work from somewhere else
+pub(crate) fn resolve_path(path: &str) -> PathBuf {
+    let path = PathBuf::from(path);
+    if path.is_absolute() || path.exists() {
+        return path;
+    }
+    let crate_path = Path::new(env!("CARGO_MANIFEST_DIR")).join(&path);
+    if crate_path.exists() {
+        return crate_path;
+    }
+    path
+}
+
+fn locate_input_with(day: usize, input: Option<&str>, input_dir: Option<String>) -> Input {
+    match input {
+        Some("-") => Input::Stdin,
+        Some(path) => Input::File(PathBuf::from(path)),
+        None => {
+            let dir = input_dir.unwrap_or(INPUT_DIR.to_string());
+            Input::File(resolve_path(&dir).join(input_file_name(day)))
+        }
+    }
+}
+
+// input is the value of --input: a file or "-" for stdin
+// without it the file is taken from $AOC_INPUT_DIR or input/
+pub fn locate_input(day: usize, input: Option<&str>) -> Input {
+    let input_dir = env::var(INPUT_DIR_ENV).ok().filter(|dir| !dir.is_empty());
+    locate_input_with(day, input, input_dir)
+}
+
+// the example of the puzzle text
+pub fn locate_example(day: usize) -> Input {
+    Input::File(resolve_path(EXAMPLE_DIR).join(example_file_name(day)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{locate_example, locate_input_with, Input};
+    use std::path::PathBuf;
+
+    #[test]
+    fn it_works() {
+        assert_eq!(locate_input_with(3, Some("-"), None), Input::Stdin);
+        assert_eq!(locate_input_with(3, Some("my.txt"), Some("/data".to_string())), Input::File(PathBuf::from("my.txt")));
+        assert_eq!(locate_input_with(3, None, Some("/data".to_string())), Input::File(PathBuf::from("/data/input_day3.txt")));
+        assert!(locate_input_with(3, None, None).to_string().ends_with("input/input_day3.txt"));
+
+        let lines = locate_example(11).read_lines().unwrap();
+        assert_eq!(lines.first().unwrap(), "Monkey 0:");
+    }
+}
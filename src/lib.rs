@@ -2,6 +2,42 @@ use std::fs::File;
 use std::io;
 use std::io::BufRead;
 
+pub mod answers;
+pub mod bench;
+pub mod cycle;
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod search;
+pub mod solver;
+
+pub use error::Error;
+
 pub fn read_lines(filepath: &str) -> io::Result<io::Lines<io::BufReader<File>>> {
     let file = File::open(filepath)?;
     Ok(io::BufReader::new(file).lines())
@@ -9,5 +45,22 @@ pub fn read_lines(filepath: &str) -> io::Result<io::Lines<io::BufReader<File>>>
 
 pub fn read_lines_as_vec(filepath: &str) -> io::Result<Vec<String>> {
     let lines = read_lines(filepath)?;
-    Ok(lines.flatten().collect())
-}
\ No newline at end of file
+    Ok(lines.map_while(Result::ok).collect())
+}
+
+// a quoted json string
+pub fn json_string(s: &str) -> String {
+    let mut json = "\"".to_string();
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
@@ -0,0 +1,177 @@
+use std::cmp::Reverse;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+use priority_queue::PriorityQueue;
+
+// how much work a search did
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct SearchStats {
+    // nodes taken from the queue and expanded
+    pub visited: usize,
+    // distinct nodes that were reached at all
+    pub seen: usize,
+}
+
+// the cheapest way to a goal, nodes start with the start node and end with the goal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    pub nodes: Vec<N>,
+    pub stats: SearchStats,
+}
+
+impl<N, C> Path<N, C> {
+    pub fn goal(&self) -> &N {
+        self.nodes.last().unwrap()
+    }
+}
+
+// follow the parents back from the goal to the start
+fn build_path<N: Eq + Hash + Clone>(parents: &HashMap<N, Option<N>>, goal: N) -> Vec<N> {
+    let mut nodes = vec![goal];
+    while let Some(Some(parent)) = parents.get(nodes.last().unwrap()) {
+        nodes.push(parent.clone());
+    }
+    nodes.reverse();
+    nodes
+}
+
+// every step costs 1, a node is queued only the first time it is reached
+pub fn bfs<N, FN, IN, FS>(start: N, mut successors: FN, mut success: FS) -> Option<Path<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+{
+    let mut stats = SearchStats::default();
+    let mut parents: HashMap<N, Option<N>> = HashMap::new();
+    let mut queue = VecDeque::new();
+    parents.insert(start.clone(), None);
+    queue.push_back(start);
+
+    while let Some(node) = queue.pop_front() {
+        stats.visited += 1;
+        if success(&node) {
+            stats.seen = parents.len();
+            let nodes = build_path(&parents, node);
+            return Some(Path { cost: nodes.len() - 1, nodes, stats });
+        }
+        for next in successors(&node) {
+            if !parents.contains_key(&next) {
+                parents.insert(next.clone(), Some(node.clone()));
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+// the distance of every node that can be reached from start
+pub fn bfs_reach<N, FN, IN>(start: N, mut successors: FN) -> HashMap<N, usize>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut dist = HashMap::new();
+    let mut queue = VecDeque::new();
+    dist.insert(start.clone(), 0);
+    queue.push_back(start);
+
+    while let Some(node) = queue.pop_front() {
+        let d = dist[&node];
+        for next in successors(&node) {
+            if !dist.contains_key(&next) {
+                dist.insert(next.clone(), d + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    dist
+}
+
+// successors return the next node together with the cost to get there
+pub fn dijkstra<N, C, FN, IN, FS>(start: N, successors: FN, success: FS) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+{
+    astar(start, successors, |_| C::default(), success)
+}
+
+// the heuristic must never overestimate the cost to a goal
+pub fn astar<N, C, FN, IN, FH, FS>(start: N, mut successors: FN, mut heuristic: FH, mut success: FS) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    let mut stats = SearchStats::default();
+    // cheapest known cost of every node
+    let mut cost: HashMap<N, C> = HashMap::new();
+    let mut parents: HashMap<N, Option<N>> = HashMap::new();
+    let mut queue = PriorityQueue::new();
+    cost.insert(start.clone(), C::default());
+    parents.insert(start.clone(), None);
+    let h = heuristic(&start);
+    queue.push(start, Reverse(h));
+
+    while let Some((node, _)) = queue.pop() {
+        stats.visited += 1;
+        let node_cost = cost[&node];
+        if success(&node) {
+            stats.seen = cost.len();
+            let nodes = build_path(&parents, node);
+            return Some(Path { cost: node_cost, nodes, stats });
+        }
+        for (next, step_cost) in successors(&node) {
+            let next_cost = node_cost + step_cost;
+            if cost.get(&next).is_some_and(|c| *c <= next_cost) {
+                continue;
+            }
+            cost.insert(next.clone(), next_cost);
+            parents.insert(next.clone(), Some(node.clone()));
+            let h = heuristic(&next);
+            queue.push_increase(next, Reverse(next_cost + h));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{astar, bfs, bfs_reach, dijkstra};
+
+    #[test]
+    fn it_works() {
+        // a line 0..10 where you can go one step or jump 3 for the cost of 2
+        let successors = |n: &i32| {
+            [(n - 1, 1), (n + 1, 1), (n + 3, 2)].into_iter().filter(|(n, _)| (0..10).contains(n)).collect::<Vec<_>>()
+        };
+
+        let path = bfs(0, |n| successors(n).into_iter().map(|(n, _)| n), |n| *n == 9).unwrap();
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.nodes, vec![0, 3, 6, 9]);
+
+        let path = dijkstra(0, successors, |n| *n == 8).unwrap();
+        assert_eq!(path.cost, 6);
+        assert_eq!(*path.goal(), 8);
+
+        let path = astar(0, successors, |n| (8 - n).abs() / 3 * 2, |n| *n == 8).unwrap();
+        assert_eq!(path.cost, 6);
+        assert!(path.stats.visited <= path.stats.seen);
+
+        assert!(bfs(0, |n| successors(n).into_iter().map(|(n, _)| n), |n| *n == 10).is_none());
+        let dist = bfs_reach(0, |n| successors(n).into_iter().map(|(n, _)| n));
+        assert_eq!(dist.len(), 10);
+        assert_eq!(dist[&7], 3);
+    }
+}
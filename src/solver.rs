@@ -0,0 +1,156 @@
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+
+use crate::Error;
+use crate::{day1, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day2, day20,
+            day21, day22, day23, day24, day25, day3, day4, day5, day6, day7, day8, day9};
+
+pub const DAYS: usize = 25;
+
+// settings from the command line that only some days use
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Options {
+    // for the days that work in parallel, None for one per core
+    pub threads: Option<usize>,
+}
+
+// every day implements this - parse the input once and
+// solve both parts from the parsed input
+// a part fails for input that parses but has no answer
+pub trait Solver {
+    type Input;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, Error>;
+    fn part1(input: &Self::Input) -> Result<String, Error>;
+    fn part2(input: &Self::Input) -> Result<String, Error>;
+
+    // a day that uses options takes them over into its input
+    fn configure(_input: &mut Self::Input, _options: &Options) {}
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => { write!(f, "1") }
+            Part::Two => { write!(f, "2") }
+        }
+    }
+}
+
+impl TryFrom<&str> for Part {
+    type Error = String;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("invalid part '{}'", s)),
+        }
+    }
+}
+
+// the answer of one part or why there is none
+pub type Answer = Result<String, Error>;
+
+// answers of one run with the time each step took
+// every part has its own answer so that one failing part does not hide the other
+#[derive(Debug, Clone)]
+pub struct Solution {
+    pub parse_time: Duration,
+    pub answers: Vec<(Part, Answer, Duration)>,
+}
+
+pub fn solve_timed<S: Solver>(lines: &[String], parts: &[Part], options: &Options) -> Result<Solution, Error> {
+    let start = Instant::now();
+    let mut input = S::parse(lines)?;
+    let parse_time = start.elapsed();
+    S::configure(&mut input, options);
+    let answers = parts.iter().map(|part| {
+        let start = Instant::now();
+        let answer = match part {
+            Part::One => S::part1(&input),
+            Part::Two => S::part2(&input),
+        };
+        (*part, answer, start.elapsed())
+    }).collect();
+    Ok(Solution { parse_time, answers })
+}
+
+pub fn solve<S: Solver>(lines: &[String], parts: &[Part], options: &Options) -> Result<Vec<(Part, Answer)>, Error> {
+    let solution = solve_timed::<S>(lines, parts, options)?;
+    Ok(solution.answers.into_iter().map(|(part, answer, _)| (part, answer)).collect())
+}
+
+// None if there is no such day
+pub fn solve_day_timed(day: usize, lines: &[String], parts: &[Part], options: &Options) -> Option<Result<Solution, Error>> {
+    let solution = match day {
+        1 => solve_timed::<day1::Day1>(lines, parts, options),
+        2 => solve_timed::<day2::Day2>(lines, parts, options),
+        3 => solve_timed::<day3::Day3>(lines, parts, options),
+        4 => solve_timed::<day4::Day4>(lines, parts, options),
+        5 => solve_timed::<day5::Day5>(lines, parts, options),
+        6 => solve_timed::<day6::Day6>(lines, parts, options),
+        7 => solve_timed::<day7::Day7>(lines, parts, options),
+        8 => solve_timed::<day8::Day8>(lines, parts, options),
+        9 => solve_timed::<day9::Day9>(lines, parts, options),
+        10 => solve_timed::<day10::Day10>(lines, parts, options),
+        11 => solve_timed::<day11::Day11>(lines, parts, options),
+        12 => solve_timed::<day12::Day12>(lines, parts, options),
+        13 => solve_timed::<day13::Day13>(lines, parts, options),
+        14 => solve_timed::<day14::Day14>(lines, parts, options),
+        15 => solve_timed::<day15::Day15>(lines, parts, options),
+        16 => solve_timed::<day16::Day16>(lines, parts, options),
+        17 => solve_timed::<day17::Day17>(lines, parts, options),
+        18 => solve_timed::<day18::Day18>(lines, parts, options),
+        19 => solve_timed::<day19::Day19>(lines, parts, options),
+        20 => solve_timed::<day20::Day20>(lines, parts, options),
+        21 => solve_timed::<day21::Day21>(lines, parts, options),
+        22 => solve_timed::<day22::Day22>(lines, parts, options),
+        23 => solve_timed::<day23::Day23>(lines, parts, options),
+        24 => solve_timed::<day24::Day24>(lines, parts, options),
+        25 => solve_timed::<day25::Day25>(lines, parts, options),
+        _ => return None,
+    };
+    Some(solution)
+}
+
+// None if there is no such day
+pub fn solve_day(day: usize, lines: &[String], parts: &[Part], options: &Options) -> Option<Result<Vec<(Part, Answer)>, Error>> {
+    let solution = solve_day_timed(day, lines, parts, options)?;
+    Some(solution.map(|solution| solution.answers.into_iter().map(|(part, answer, _)| (part, answer)).collect()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{solve_day, Options, Part};
+
+    #[test]
+    fn it_works() {
+        let lines = ["A Y",
+                     "B X",
+                     "C Z"].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        let result = solve_day(2, &lines, &[Part::One, Part::Two], &Options::default()).unwrap();
+        assert_eq!(result, Ok(vec![(Part::One, Ok("15".to_string())), (Part::Two, Ok("12".to_string()))]));
+        let result = solve_day(2, &lines, &[Part::Two], &Options::default()).unwrap();
+        assert_eq!(result, Ok(vec![(Part::Two, Ok("12".to_string()))]));
+        assert!(solve_day(26, &lines, &[Part::One], &Options::default()).is_none());
+
+        let lines = ["A Y", "B"].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let result = solve_day(2, &lines, &[Part::One], &Options::default()).unwrap();
+        assert_eq!(result.unwrap_err().to_string(), "day 2 line 2: missing field 2");
+
+        // parses fine but root can not be computed, humn still can
+        let lines = ["root: pppw + sjmn", "pppw: humn / dvpt", "dvpt: 3", "sjmn: 4", "humn: 8"]
+            .iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let result = solve_day(21, &lines, &[Part::One, Part::Two], &Options::default()).unwrap().unwrap();
+        assert_eq!(result[0].1.as_ref().unwrap_err().to_string(), "day 21: pppw: 8 / 3 is not a whole number");
+        assert_eq!(result[1].1, Ok("12".to_string()));
+    }
+}